@@ -190,18 +190,43 @@ mod bundle {
             .no_copy("clingo_control")
             .no_copy("clingo_options")
             .allowlist_type("clingo_control.*")
+            .allowlist_type("clingo_backend.*")
+            .allowlist_type("clingo_external_type.*")
+            .allowlist_type("clingo_weighted_literal.*")
             .allowlist_type("clingo_options.*")
             .allowlist_type("clingo_model.*")
             .allowlist_type("clingo_solve_handle.*")
             .allowlist_type("clingo_solve_result_.*")
+            .allowlist_type("clingo_solve_event.*")
             .allowlist_type("clingo_symbol.*")
             .allowlist_type("clingo_error.*")
+            .allowlist_type("clingo_configuration.*")
+            .allowlist_type("clingo_symbolic_atom.*")
+            .allowlist_type("clingo_statistics.*")
+            .allowlist_type("clingo_signature_t")
+            .allowlist_type("clingo_theory_atoms.*")
+            .allowlist_type("clingo_truth_value.*")
+            .allowlist_type("clingo_weight_t")
+            .allowlist_type("clingo_warning.*")
+            .allowlist_type("clingo_logger_t")
+            .allowlist_type("clingo_propagat.*")
+            .allowlist_type("clingo_assignment_t")
+            .allowlist_type("clingo_clause_type.*")
             .allowlist_function("clingo_control_.*")
+            .allowlist_function("clingo_backend_.*")
             .allowlist_function("clingo_solve_handle_.*")
             .allowlist_function("clingo_model_.*")
             .allowlist_function("clingo_symbol_.*")
             .allowlist_function("clingo_configuration_.*")
+            .allowlist_function("clingo_symbolic_atoms_.*")
+            .allowlist_function("clingo_statistics_.*")
+            .allowlist_function("clingo_signature_.*")
+            .allowlist_function("clingo_theory_atoms_.*")
             .allowlist_function("clingo_error_.*")
+            .allowlist_function("clingo_propagate_.*")
+            .allowlist_function("clingo_assignment_.*")
+            .allowlist_function("clingo_set_error")
+            .allowlist_function("clingo_parse_term")
             .allowlist_function("clingo_version")
             .generate()
             .expect("Unable to generate bindings");
@@ -3,14 +3,20 @@
 //! It is used to share state such as configuration and database connections
 //! across different requests.
 
+use std::collections::HashSet;
 use std::sync::{Arc, Mutex};
 
 use rusqlite::Connection;
 
+use crate::clingo::logger::LogMessage;
 use crate::core::{
     config::Config,
     domain::{item::Item, template::Template},
 };
+use crate::optimization::assumption::Assumption;
+use crate::optimization::inspection::InstanceSummary;
+use crate::optimization::session::{SessionCommand, SessionHandle};
+use crate::optimization::statistics::SolveStatistics;
 
 /// This struct holds the application state, including configuration,
 #[derive(Clone)]
@@ -26,4 +32,44 @@ pub struct AppState {
 
     /// All items that can be used in the current template.
     pub items: Arc<Mutex<Vec<Arc<Item>>>>,
+
+    /// The IDs of the items the user excluded from the optimization.
+    pub excluded_items: Arc<Mutex<HashSet<i32>>>,
+
+    /// The optimization session of the current template, if one is running.
+    pub session: Arc<Mutex<Option<SessionHandle>>>,
+
+    /// The summary of the grounded problem instance of the current session.
+    pub instance_summary: Arc<Mutex<Option<InstanceSummary>>>,
+
+    /// The solver statistics of the last optimization run.
+    pub statistics: Arc<Mutex<Option<SolveStatistics>>>,
+
+    /// The conflicting pins of the last optimization run, if it was unsatisfiable.
+    pub unsat_core: Arc<Mutex<Option<Vec<Assumption>>>>,
+
+    /// The messages clingo reported since the instance of the current session was grounded.
+    pub clingo_messages: Arc<Mutex<Vec<LogMessage>>>,
+}
+
+impl AppState {
+    /// Sends a command to the optimization session of the current template.
+    ///
+    /// Commands are dropped if no session is running. The session is started
+    /// with the current template, so nothing is lost in that case.
+    ///
+    /// # Parameters
+    /// - `command`: The command to send.
+    pub fn send_session_command(&self, command: SessionCommand) {
+        match self.session.lock() {
+            Ok(guard) => {
+                if let Some(session) = guard.as_ref()
+                    && let Err(e) = session.send(command)
+                {
+                    println!("Warning: {e}");
+                }
+            }
+            Err(_) => println!("Warning: Session mutex was poisoned."),
+        }
+    }
 }
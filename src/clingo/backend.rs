@@ -0,0 +1,232 @@
+//! This module provides a safe Rust interface for interacting with
+//! the Clingo backend API.
+//!
+//! The backend adds rules, atoms and minimize statements directly to the ground
+//! program, which avoids printing and parsing large numbers of facts.
+//! Atoms added with a symbol are also used for the instantiation of rules
+//! in subsequent ground calls.
+
+use std::{marker::PhantomData, ptr::NonNull};
+
+use super::{
+    bindings::{
+        clingo_backend_add_atom, clingo_backend_begin, clingo_backend_end, clingo_backend_external,
+        clingo_backend_minimize, clingo_backend_rule, clingo_backend_t,
+        clingo_external_type_e_clingo_external_type_false,
+        clingo_external_type_e_clingo_external_type_free,
+        clingo_external_type_e_clingo_external_type_release,
+        clingo_external_type_e_clingo_external_type_true, clingo_external_type_t,
+        clingo_weighted_literal_t,
+    },
+    control::Control,
+    error::ClingoError,
+    literal::{Atom, Literal},
+    symbol::Symbol,
+};
+
+/// The initial value of an external atom declared through the backend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExternalType {
+    /// The atom is free, the solver may choose its value.
+    Free,
+    /// The atom is true.
+    True,
+    /// The atom is false.
+    False,
+    /// The atom is released and becomes permanently false.
+    Release,
+}
+
+impl From<ExternalType> for clingo_external_type_t {
+    fn from(value: ExternalType) -> Self {
+        (match value {
+            ExternalType::Free => clingo_external_type_e_clingo_external_type_free,
+            ExternalType::True => clingo_external_type_e_clingo_external_type_true,
+            ExternalType::False => clingo_external_type_e_clingo_external_type_false,
+            ExternalType::Release => clingo_external_type_e_clingo_external_type_release,
+        }) as clingo_external_type_t
+    }
+}
+
+/// A literal with a weight, used in minimize statements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeightedLiteral {
+    /// The literal.
+    pub literal: Literal,
+    /// The weight of the literal.
+    pub weight: i32,
+}
+
+/// The backend of a control.
+///
+/// The backend is opened when it is created and closed when it is dropped.
+/// It borrows the control, so no other operation can run while it is open.
+pub struct Backend<'a> {
+    inner: NonNull<clingo_backend_t>,
+    _control: PhantomData<&'a Control>,
+}
+
+impl<'a> Backend<'a> {
+    /// Opens the backend for adding to the ground program.
+    ///
+    /// # Parameters
+    /// - `inner`: A non-null pointer to `clingo_backend_t`.
+    ///
+    /// # Returns
+    /// - `Ok(Backend)` if the backend was opened successfully.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the backend could not be opened.
+    pub fn new(inner: NonNull<clingo_backend_t>) -> Result<Self, ClingoError> {
+        let success = unsafe { clingo_backend_begin(inner.as_ptr()) };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_backend_begin() failed".to_owned(),
+            ));
+        }
+
+        Ok(Backend {
+            inner,
+            _control: PhantomData,
+        })
+    }
+
+    /// Adds a program atom.
+    ///
+    /// # Parameters
+    /// - `symbol`: The symbol of the atom. If given, the atom of an existing symbol is
+    ///   returned, otherwise a fresh atom without a symbol is created.
+    ///
+    /// # Returns
+    /// - `Ok(Atom)` containing the program atom.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the atom could not be added.
+    pub fn add_atom(&mut self, symbol: Option<&Symbol>) -> Result<Atom, ClingoError> {
+        let mut raw = symbol.map(Symbol::raw);
+        let symbol_ptr = raw
+            .as_mut()
+            .map_or(std::ptr::null_mut(), |raw| raw as *mut _);
+
+        let mut atom = 0;
+        let success =
+            unsafe { clingo_backend_add_atom(self.inner.as_ptr(), symbol_ptr, &mut atom) };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_backend_add_atom() failed".to_owned(),
+            ));
+        }
+
+        Ok(atom)
+    }
+
+    /// Adds a rule to the program.
+    ///
+    /// A rule with a single head atom and an empty body is a fact.
+    ///
+    /// # Parameters
+    /// - `choice`: Whether the head is a choice, i.e. `{ a; b } :- body.`
+    /// - `head`: The head atoms, a disjunction unless `choice` is set.
+    /// - `body`: The body literals, a conjunction.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the rule was added successfully.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the rule could not be added.
+    pub fn rule(
+        &mut self,
+        choice: bool,
+        head: &[Atom],
+        body: &[Literal],
+    ) -> Result<(), ClingoError> {
+        let success = unsafe {
+            clingo_backend_rule(
+                self.inner.as_ptr(),
+                choice,
+                head.as_ptr(),
+                head.len(),
+                body.as_ptr(),
+                body.len(),
+            )
+        };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_backend_rule() failed".to_owned(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Adds a minimize statement to the program.
+    ///
+    /// # Parameters
+    /// - `priority`: The priority of the statement, higher priorities are optimized first.
+    /// - `literals`: The weighted literals whose sum is minimized.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the statement was added successfully.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the statement could not be added.
+    pub fn minimize(
+        &mut self,
+        priority: i32,
+        literals: &[WeightedLiteral],
+    ) -> Result<(), ClingoError> {
+        let literals = literals
+            .iter()
+            .map(|literal| clingo_weighted_literal_t {
+                literal: literal.literal,
+                weight: literal.weight,
+            })
+            .collect::<Vec<_>>();
+
+        let success = unsafe {
+            clingo_backend_minimize(
+                self.inner.as_ptr(),
+                priority,
+                literals.as_ptr(),
+                literals.len(),
+            )
+        };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_backend_minimize() failed".to_owned(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Declares an atom as external.
+    ///
+    /// # Parameters
+    /// - `atom`: The atom to declare.
+    /// - `value`: The initial value of the external atom.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the atom was declared successfully.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the atom could not be declared.
+    pub fn external(&mut self, atom: Atom, value: ExternalType) -> Result<(), ClingoError> {
+        let success = unsafe { clingo_backend_external(self.inner.as_ptr(), atom, value.into()) };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_backend_external() failed".to_owned(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for Backend<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            clingo_backend_end(self.inner.as_ptr());
+        }
+    }
+}
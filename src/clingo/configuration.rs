@@ -1,21 +1,47 @@
 //! This module provides a safe Rust interface for interacting with
 //! the Clingo configuration API.
+//!
+//! Clingo organizes its configuration as a tree of keys. Inner nodes are maps
+//! (and sometimes arrays, e.g. one `solver` entry per thread) and leaves hold
+//! string values. Keys can be addressed with dotted paths such as
+//! `solve.models` or `solver.heuristic`, exactly as they are named on the
+//! clingo command line (with dashes replaced by underscores).
 
-use std::{ffi::CString, ptr::NonNull};
+use std::{
+    ffi::{CStr, CString},
+    ptr::NonNull,
+};
 
 use super::{
     bindings::{
-        clingo_configuration_map_at, clingo_configuration_root, clingo_configuration_t,
-        clingo_configuration_value_set,
+        clingo_configuration_array_size, clingo_configuration_description,
+        clingo_configuration_map_at, clingo_configuration_map_size,
+        clingo_configuration_map_subkey_name, clingo_configuration_root, clingo_configuration_t,
+        clingo_configuration_type, clingo_configuration_type_bitset_t,
+        clingo_configuration_type_e_clingo_configuration_type_array,
+        clingo_configuration_type_e_clingo_configuration_type_map,
+        clingo_configuration_type_e_clingo_configuration_type_value,
+        clingo_configuration_value_get, clingo_configuration_value_get_size,
+        clingo_configuration_value_is_assigned, clingo_configuration_value_set, clingo_id_t,
     },
     error::ClingoError,
 };
 
 /// Clingo configuration keys
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ConfigKey {
     /// Key for setting the number of solve models.
     SolveModels,
+    /// Key for the number of threads and the parallel mode (`-t`, `--parallel-mode`).
+    ParallelMode,
+    /// Key for the optimization mode (`--opt-mode`).
+    OptMode,
+    /// Key for the optimization strategy (`--opt-strategy`).
+    OptStrategy,
+    /// Key for the decision heuristic (`--heuristic`).
+    Heuristic,
+    /// Key for the restart schedule (`--restarts`).
+    Restarts,
 }
 
 impl ConfigKey {
@@ -26,6 +52,133 @@ impl ConfigKey {
     pub fn as_str(&self) -> &'static str {
         match self {
             ConfigKey::SolveModels => "solve.models",
+            ConfigKey::ParallelMode => "solve.parallel_mode",
+            ConfigKey::OptMode => "solve.opt_mode",
+            ConfigKey::OptStrategy => "solver.opt_strategy",
+            ConfigKey::Heuristic => "solver.heuristic",
+            ConfigKey::Restarts => "solver.restarts",
+        }
+    }
+}
+
+/// How multiple solver threads cooperate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParallelMode {
+    /// Every thread solves the whole problem with a different configuration.
+    Compete,
+    /// The search space is split between the threads.
+    Split,
+}
+
+impl ParallelMode {
+    /// Returns the clingo name of the parallel mode.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ParallelMode::Compete => "compete",
+            ParallelMode::Split => "split",
+        }
+    }
+}
+
+/// The optimization mode of the solver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptMode {
+    /// Find an optimal model.
+    Opt,
+    /// Enumerate models with a cost less than or equal to a bound.
+    Enum,
+    /// Find optimum, then enumerate all optimal models.
+    OptN,
+    /// Ignore optimize statements.
+    Ignore,
+}
+
+impl OptMode {
+    /// Returns the clingo name of the optimization mode.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OptMode::Opt => "opt",
+            OptMode::Enum => "enum",
+            OptMode::OptN => "optN",
+            OptMode::Ignore => "ignore",
+        }
+    }
+}
+
+/// The optimization strategy of the solver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptStrategy {
+    /// Model-guided branch and bound optimization.
+    BranchAndBound,
+    /// Core-guided (unsatisfiable core) optimization.
+    CoreGuided,
+}
+
+impl OptStrategy {
+    /// Returns the clingo name of the optimization strategy.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OptStrategy::BranchAndBound => "bb",
+            OptStrategy::CoreGuided => "usc",
+        }
+    }
+}
+
+/// The decision heuristic of the solver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Heuristic {
+    /// BerkMin-like heuristic.
+    Berkmin,
+    /// Siege-like heuristic.
+    Vmtf,
+    /// Chaff-like heuristic.
+    Vsids,
+    /// Domain specific heuristic, driven by `#heuristic` statements.
+    Domain,
+    /// Smodels-like lookahead heuristic.
+    Unit,
+    /// Select the first free variable.
+    None,
+}
+
+impl Heuristic {
+    /// Returns the clingo name of the heuristic.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Heuristic::Berkmin => "berkmin",
+            Heuristic::Vmtf => "vmtf",
+            Heuristic::Vsids => "vsids",
+            Heuristic::Domain => "domain",
+            Heuristic::Unit => "unit",
+            Heuristic::None => "none",
+        }
+    }
+}
+
+/// The restart schedule of the solver.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Restarts {
+    /// Disable restarts.
+    No,
+    /// Restart every `n` conflicts.
+    Fixed(u32),
+    /// Luby sequence scaled by `n` conflicts.
+    Luby(u32),
+    /// Geometric sequence starting at `n` conflicts, growing by the given factor.
+    Geometric(u32, f64),
+    /// Dynamic restarts based on a moving average over `n` conflicts and the given factor.
+    Dynamic(u32, f64),
+}
+
+impl Restarts {
+    /// Returns the clingo representation of the restart schedule.
+    pub fn to_value(&self) -> String {
+        match self {
+            Restarts::No => "no".to_string(),
+            Restarts::Fixed(n) => format!("F,{n}"),
+            Restarts::Luby(n) => format!("L,{n}"),
+            Restarts::Geometric(n, factor) => format!("x,{n},{factor}"),
+            Restarts::Dynamic(n, factor) => format!("D,{n},{factor}"),
         }
     }
 }
@@ -36,7 +189,7 @@ impl ConfigKey {
 /// the underlying `clingo_configuration_t` pointer.
 pub struct Configuration {
     inner: NonNull<clingo_configuration_t>,
-    root: u32,
+    root: clingo_id_t,
 }
 
 impl Configuration {
@@ -72,38 +225,317 @@ impl Configuration {
     /// - `Ok(())` if successful.
     /// - `Err(ClingoError)` if there was an error setting the value.
     pub fn set_key_to_value(&mut self, key: ConfigKey, value: &str) -> Result<(), ClingoError> {
-        let mut models_key = 0;
-        let key_name = CString::new(ConfigKey::SolveModels.as_str())?;
-
-        let cet_key_success = unsafe {
-            clingo_configuration_map_at(
-                self.inner.as_ptr(),
-                self.root,
-                key_name.as_ptr(),
-                &mut models_key,
-            )
+        self.set(key.as_str(), value)
+    }
+
+    /// Retrieves the current value of a configuration key.
+    ///
+    /// # Parameters
+    /// - `key`: The configuration key to read.
+    ///
+    /// # Returns
+    /// - `Ok(Some(String))` if the key has a value assigned.
+    /// - `Ok(None)` if the key has no value assigned.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the key does not exist or could not be read.
+    pub fn get_key(&self, key: ConfigKey) -> Result<Option<String>, ClingoError> {
+        self.get(key.as_str())
+    }
+
+    /// Sets the maximum number of models to compute, `0` meaning all models.
+    ///
+    /// # Parameters
+    /// - `models`: The number of models.
+    pub fn set_models(&mut self, models: u32) -> Result<(), ClingoError> {
+        self.set_key_to_value(ConfigKey::SolveModels, &models.to_string())
+    }
+
+    /// Sets the number of solver threads and how they cooperate.
+    ///
+    /// # Parameters
+    /// - `threads`: The number of threads, at least one.
+    /// - `mode`: The parallel mode used when more than one thread is requested.
+    pub fn set_parallel_mode(
+        &mut self,
+        threads: u32,
+        mode: ParallelMode,
+    ) -> Result<(), ClingoError> {
+        let value = format!("{},{}", threads.max(1), mode.as_str());
+        self.set_key_to_value(ConfigKey::ParallelMode, &value)
+    }
+
+    /// Sets the optimization mode.
+    ///
+    /// # Parameters
+    /// - `mode`: The optimization mode.
+    pub fn set_opt_mode(&mut self, mode: OptMode) -> Result<(), ClingoError> {
+        self.set_key_to_value(ConfigKey::OptMode, mode.as_str())
+    }
+
+    /// Sets the optimization strategy.
+    ///
+    /// # Parameters
+    /// - `strategy`: The optimization strategy.
+    pub fn set_opt_strategy(&mut self, strategy: OptStrategy) -> Result<(), ClingoError> {
+        self.set_key_to_value(ConfigKey::OptStrategy, strategy.as_str())
+    }
+
+    /// Sets the decision heuristic.
+    ///
+    /// # Parameters
+    /// - `heuristic`: The decision heuristic.
+    pub fn set_heuristic(&mut self, heuristic: Heuristic) -> Result<(), ClingoError> {
+        self.set_key_to_value(ConfigKey::Heuristic, heuristic.as_str())
+    }
+
+    /// Sets the restart schedule.
+    ///
+    /// # Parameters
+    /// - `restarts`: The restart schedule.
+    pub fn set_restarts(&mut self, restarts: Restarts) -> Result<(), ClingoError> {
+        self.set_key_to_value(ConfigKey::Restarts, &restarts.to_value())
+    }
+
+    /// Sets the value of an arbitrary key, addressed by its dotted path.
+    ///
+    /// # Parameters
+    /// - `path`: The dotted path of the key, e.g. `solver.sign_def`.
+    /// - `value`: The value to set for the key.
+    ///
+    /// # Returns
+    /// - `Ok(())` if successful.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the key does not exist or the value was rejected.
+    pub fn set(&mut self, path: &str, value: &str) -> Result<(), ClingoError> {
+        let key = self.key(path)?;
+        let value = CString::new(value)?;
+
+        let success =
+            unsafe { clingo_configuration_value_set(self.inner.as_ptr(), key, value.as_ptr()) };
+
+        if !success {
+            return Err(ClingoError::new_internal(format!(
+                "Failed to set value for '{path}'"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Retrieves the value of an arbitrary key, addressed by its dotted path.
+    ///
+    /// # Parameters
+    /// - `path`: The dotted path of the key.
+    ///
+    /// # Returns
+    /// - `Ok(Some(String))` if the key has a value assigned.
+    /// - `Ok(None)` if the key has no value assigned.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the key does not exist, is not a value
+    ///   or could not be read.
+    pub fn get(&self, path: &str) -> Result<Option<String>, ClingoError> {
+        let key = self.key(path)?;
+
+        if !self.has_type(
+            key,
+            clingo_configuration_type_e_clingo_configuration_type_value
+                as clingo_configuration_type_bitset_t,
+        )? {
+            return Err(ClingoError::new_type_error(
+                "Configuration key does not hold a value",
+            ));
+        }
+
+        let mut assigned = false;
+        let success = unsafe {
+            clingo_configuration_value_is_assigned(self.inner.as_ptr(), key, &mut assigned)
+        };
+        if !success {
+            return Err(ClingoError::new_internal(format!(
+                "Failed to check assignment of '{path}'"
+            )));
+        }
+        if !assigned {
+            return Ok(None);
+        }
+
+        let mut size = 0;
+        let success =
+            unsafe { clingo_configuration_value_get_size(self.inner.as_ptr(), key, &mut size) };
+        if !success {
+            return Err(ClingoError::new_internal(format!(
+                "Failed to get value size of '{path}'"
+            )));
+        }
+
+        let mut buffer = vec![0 as std::ffi::c_char; size.max(1)];
+        let success = unsafe {
+            clingo_configuration_value_get(self.inner.as_ptr(), key, buffer.as_mut_ptr(), size)
         };
+        if !success {
+            return Err(ClingoError::new_internal(format!(
+                "Failed to get value of '{path}'"
+            )));
+        }
+
+        let value = unsafe { CStr::from_ptr(buffer.as_ptr()) };
+        Ok(Some(value.to_string_lossy().into_owned()))
+    }
+
+    /// Lists the names of the subkeys of a map key.
+    ///
+    /// # Parameters
+    /// - `path`: The dotted path of the key, or an empty string for the root.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<String>)` containing the subkey names in clingo's order.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the key does not exist or is not a map.
+    pub fn subkeys(&self, path: &str) -> Result<Vec<String>, ClingoError> {
+        let key = self.key(path)?;
+
+        if !self.has_type(
+            key,
+            clingo_configuration_type_e_clingo_configuration_type_map
+                as clingo_configuration_type_bitset_t,
+        )? {
+            return Err(ClingoError::new_type_error(
+                "Configuration key is not a map",
+            ));
+        }
 
-        if !cet_key_success {
+        let mut size = 0;
+        let success = unsafe { clingo_configuration_map_size(self.inner.as_ptr(), key, &mut size) };
+        if !success {
+            return Err(ClingoError::new_internal(format!(
+                "Failed to get number of subkeys of '{path}'"
+            )));
+        }
+
+        let mut names = Vec::with_capacity(size);
+        for offset in 0..size {
+            let mut name_ptr = std::ptr::null();
+            let success = unsafe {
+                clingo_configuration_map_subkey_name(
+                    self.inner.as_ptr(),
+                    key,
+                    offset,
+                    &mut name_ptr,
+                )
+            };
+            if !success || name_ptr.is_null() {
+                return Err(ClingoError::new_internal(format!(
+                    "Failed to get subkey {offset} of '{path}'"
+                )));
+            }
+            names.push(
+                unsafe { CStr::from_ptr(name_ptr) }
+                    .to_string_lossy()
+                    .into_owned(),
+            );
+        }
+
+        Ok(names)
+    }
+
+    /// Returns the number of entries of an array key, such as `solver`.
+    ///
+    /// # Parameters
+    /// - `path`: The dotted path of the key.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the key does not exist or is not an array.
+    pub fn array_size(&self, path: &str) -> Result<usize, ClingoError> {
+        let key = self.key(path)?;
+
+        if !self.has_type(
+            key,
+            clingo_configuration_type_e_clingo_configuration_type_array
+                as clingo_configuration_type_bitset_t,
+        )? {
+            return Err(ClingoError::new_type_error(
+                "Configuration key is not an array",
+            ));
+        }
+
+        let mut size = 0;
+        let success =
+            unsafe { clingo_configuration_array_size(self.inner.as_ptr(), key, &mut size) };
+        if !success {
+            return Err(ClingoError::new_internal(format!(
+                "Failed to get array size of '{path}'"
+            )));
+        }
+
+        Ok(size)
+    }
+
+    /// Returns clingo's description of a key.
+    ///
+    /// # Parameters
+    /// - `path`: The dotted path of the key.
+    pub fn description(&self, path: &str) -> Result<String, ClingoError> {
+        let key = self.key(path)?;
+
+        let mut description = std::ptr::null();
+        let success =
+            unsafe { clingo_configuration_description(self.inner.as_ptr(), key, &mut description) };
+        if !success {
             return Err(ClingoError::new_internal(format!(
-                "Failed to get key for '{}'",
-                key.as_str()
+                "Failed to get description of '{path}'"
             )));
         }
+        if description.is_null() {
+            return Ok(String::new());
+        }
+
+        Ok(unsafe { CStr::from_ptr(description) }
+            .to_string_lossy()
+            .into_owned())
+    }
 
-        let value = CString::new(value.to_string())?;
+    /// Resolves a dotted path to a clingo key id.
+    ///
+    /// An empty path resolves to the root key.
+    fn key(&self, path: &str) -> Result<clingo_id_t, ClingoError> {
+        if path.is_empty() {
+            return Ok(self.root);
+        }
 
-        let set_value_success = unsafe {
-            clingo_configuration_value_set(self.inner.as_ptr(), models_key, value.as_ptr())
+        let mut key = 0;
+        let key_name = CString::new(path)?;
+
+        let success = unsafe {
+            clingo_configuration_map_at(self.inner.as_ptr(), self.root, key_name.as_ptr(), &mut key)
         };
 
-        if !set_value_success {
+        if !success {
             return Err(ClingoError::new_internal(format!(
-                "Failed to set value for '{}'",
-                key.as_str()
+                "Failed to get key for '{path}'"
             )));
         }
 
-        Ok(())
+        Ok(key)
+    }
+
+    /// Checks whether a key has the given configuration type.
+    fn has_type(
+        &self,
+        key: clingo_id_t,
+        kind: clingo_configuration_type_bitset_t,
+    ) -> Result<bool, ClingoError> {
+        let mut types = 0;
+        let success = unsafe { clingo_configuration_type(self.inner.as_ptr(), key, &mut types) };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Failed to get type of configuration key".to_owned(),
+            ));
+        }
+
+        Ok(types & kind != 0)
     }
 }
@@ -1,24 +1,94 @@
 //! This module provides a safe Rust interface for interacting with
 //! the Clingo control API.
 
-use std::{ffi::CString, ptr::NonNull};
+use std::{
+    any::Any,
+    ffi::{CString, c_void},
+    fmt,
+    panic::{AssertUnwindSafe, catch_unwind},
+    ptr::NonNull,
+};
 
 use super::{
+    backend::Backend,
     bindings::{
+        clingo_control_add, clingo_control_assign_external, clingo_control_backend,
         clingo_control_configuration, clingo_control_free, clingo_control_ground,
-        clingo_control_load, clingo_control_new, clingo_control_solve, clingo_control_t,
-        clingo_part,
+        clingo_control_load, clingo_control_new, clingo_control_register_propagator,
+        clingo_control_release_external, clingo_control_solve, clingo_control_statistics,
+        clingo_control_symbolic_atoms, clingo_control_t, clingo_control_theory_atoms,
+        clingo_error_e_clingo_error_runtime, clingo_model_t, clingo_part, clingo_set_error,
+        clingo_solve_event_type_e_clingo_solve_event_type_finish,
+        clingo_solve_event_type_e_clingo_solve_event_type_model,
+        clingo_solve_event_type_e_clingo_solve_event_type_statistics,
+        clingo_solve_event_type_e_clingo_solve_event_type_unsat, clingo_solve_event_type_t,
+        clingo_solve_result_bitset_t, clingo_symbol_t,
     },
     configuration::Configuration,
     error::ClingoError,
+    literal::{Literal, TruthValue},
+    logger::{LogMessage, Logger, logger_callback},
     model::Model,
+    propagator::{self, Propagator, Registration},
     solve_handle::SolveHandle,
+    solve_result::SolveResult,
+    statistics::Statistics,
+    symbol::Symbol,
+    symbolic_atoms::SymbolicAtoms,
+    theory_atoms::TheoryAtoms,
 };
 
+/// The maximum number of messages clingo reports per control.
+const MESSAGE_LIMIT: u32 = 50;
+
+/// Options of the clingo executable that the clingo library does not understand.
+///
+/// Long options match with and without a value, e.g. `--time-limit=30`.
+pub const APP_OPTIONS: &[&str] = &[
+    "--time-limit",
+    "--outf",
+    "--out-atomf",
+    "--out-ifs",
+    "--out-hide-aux",
+    "--quiet",
+    "-q",
+    "--verbose",
+    "-V",
+    "--pre",
+    "--print-portfolio",
+    "--fast-exit",
+];
+
+/// Checks whether an argument is an option of the clingo executable, see [`APP_OPTIONS`].
+fn is_app_option(arg: &str) -> bool {
+    APP_OPTIONS.iter().any(|option| {
+        if option.starts_with("--") {
+            arg == *option || arg.starts_with(&format!("{option}="))
+        } else {
+            arg.starts_with(option)
+        }
+    })
+}
+
 /// The Clingo control structure.
-#[derive(Debug)]
+///
+/// Messages clingo reports through its logger, such as undefined atoms, are collected
+/// by the control. They are attached to the error if a call fails and can be taken
+/// with [`Control::messages`] otherwise.
+///
+/// Registered propagators are owned by the control and dropped after it is freed.
+///
+/// # Threading
+/// A control may be moved to another thread, but it is not `Sync` and must only be
+/// used by one thread at a time. Solve handles borrow the control, so it outlives
+/// every running search. Clingo itself solves with multiple threads if configured,
+/// see [`Configuration::set_parallel_mode`]. Everything clingo may call from its
+/// solver threads is thread-safe: solve event callbacks are `Send` and never run
+/// concurrently, the logger is synchronized, and propagators are `Send + Sync`.
 pub struct Control {
     inner: NonNull<clingo_control_t>,
+    logger: Box<Logger>,
+    propagators: Vec<Box<dyn Any + Send + Sync>>,
 }
 
 impl Control {
@@ -31,30 +101,79 @@ impl Control {
     /// - `Err(ClingoError)` if there was an error during the creation process
     ///   of the control.
     pub fn new() -> Result<Self, ClingoError> {
+        Self::with_args::<&str>(&[])
+    }
+
+    /// Creates a new `Control` instance configured by clingo command-line arguments.
+    ///
+    /// The arguments are passed to clingo as they would be given on the command line,
+    /// without the program name, e.g. `["--opt-mode=optN", "-t", "4"]`.
+    /// Only options understood by the clingo library are accepted. Options that belong
+    /// to the clingo executable itself, such as `--time-limit` or `--outf`, are rejected,
+    /// see [`APP_OPTIONS`].
+    ///
+    /// # Parameters
+    /// - `args`: The command-line arguments to pass to clingo.
+    ///
+    /// # Returns
+    /// - `Ok(Control)` if the control was created successfully.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError::UnsupportedArgument)` if an argument is an option of the
+    ///   clingo executable.
+    /// - `Err(ClingoError)` if an argument contains a null byte, or if clingo
+    ///   failed to parse the arguments or to create the control. Clingo's
+    ///   parse error is contained in the internal message of the error.
+    pub fn with_args<S: AsRef<str>>(args: &[S]) -> Result<Self, ClingoError> {
+        if let Some(argument) = args
+            .iter()
+            .map(AsRef::as_ref)
+            .find(|arg| is_app_option(arg))
+        {
+            return Err(ClingoError::UnsupportedArgument {
+                argument: argument.to_owned(),
+            });
+        }
+
+        let c_args = args
+            .iter()
+            .map(|arg| CString::new(arg.as_ref()))
+            .collect::<Result<Vec<CString>, _>>()?;
+        let c_arg_pointers = c_args.iter().map(|arg| arg.as_ptr()).collect::<Vec<_>>();
+
+        let logger = Box::new(Logger::default());
+        let logger_data = &*logger as *const Logger as *mut c_void;
+
         let mut control_pointer: *mut clingo_control_t = std::ptr::null_mut();
 
         let success = unsafe {
             clingo_control_new(
-                std::ptr::null(),
-                0,
-                None,
-                std::ptr::null_mut(),
-                0,
+                c_arg_pointers.as_ptr(),
+                c_arg_pointers.len(),
+                Some(logger_callback),
+                logger_data,
+                MESSAGE_LIMIT,
                 &mut control_pointer,
             )
         };
 
         if !success {
-            return Err(ClingoError::new_internal(
-                "Failed to create clingo control".to_owned(),
-            ));
+            return Err(ClingoError::new_internal(format!(
+                "Failed to create clingo control with arguments {:?}",
+                args.iter().map(|arg| arg.as_ref()).collect::<Vec<_>>()
+            ))
+            .with_messages(logger.take()));
         }
 
         let inner = NonNull::new(control_pointer).ok_or_else(|| {
             ClingoError::new_internal("Received null pointer for clingo control".to_owned())
         })?;
 
-        Ok(Control { inner })
+        Ok(Control {
+            inner,
+            logger,
+            propagators: Vec::new(),
+        })
     }
 
     /// Loads a logic program from a file into the control.
@@ -74,45 +193,101 @@ impl Control {
         let success = unsafe { clingo_control_load(self.inner.as_ptr(), c_file.as_ptr()) };
 
         if !success {
-            return Err(ClingoError::new_internal(
-                "Failed to load program into control".to_owned(),
-            ));
+            return Err(self.error("Failed to load program into control".to_owned()));
+        }
+
+        Ok(())
+    }
+
+    /// Adds a logic program given as text to the control.
+    ///
+    /// The program is added to the program part with the given name, which
+    /// can later be grounded. Rules outside of any `#program` directive
+    /// in the text belong to this part.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the program part, e.g. `base`.
+    /// - `params`: The names of the parameters of the program part.
+    /// - `program`: The logic program text.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the program was added successfully.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if a string contains a null byte or the program
+    ///   could not be parsed.
+    pub fn add(&self, name: &str, params: &[&str], program: &str) -> Result<(), ClingoError> {
+        let c_name = CString::new(name)?;
+        let c_params = params
+            .iter()
+            .map(|param| CString::new(*param))
+            .collect::<Result<Vec<CString>, _>>()?;
+        let c_param_pointers = c_params
+            .iter()
+            .map(|param| param.as_ptr())
+            .collect::<Vec<_>>();
+        let c_program = CString::new(program)?;
+
+        let success = unsafe {
+            clingo_control_add(
+                self.inner.as_ptr(),
+                c_name.as_ptr(),
+                c_param_pointers.as_ptr(),
+                c_param_pointers.len(),
+                c_program.as_ptr(),
+            )
+        };
+
+        if !success {
+            return Err(self.error(format!("Failed to add program part '{name}' to control")));
         }
 
         Ok(())
     }
 
-    /// Grounds the logic program in the control.
+    /// Grounds the given program parts in the control.
+    ///
+    /// Each part is given by its name and the symbols its parameters are
+    /// instantiated with, e.g. `("base", vec![])` or `("slot", vec![Symbol::create_number(35)])`
+    /// for a part declared as `#program slot(s).`.
+    ///
+    /// # Parameters
+    /// - `parts`: The program parts to ground, given as name and parameter symbols.
     ///
     /// # Returns
     /// - `Ok(())` if the program was grounded successfully.
     ///
     /// # Errors
-    /// - `Err(ClingoError)` if there was an error during the grounding process
-    ///   of the program.
-    pub fn ground(&self) -> Result<(), ClingoError> {
-        let name_cstr = CString::new("base").unwrap();
-        let part = clingo_part {
-            name: name_cstr.as_ptr(),
-            params: std::ptr::null(),
-            size: 0,
-        };
-        let parts = [part];
+    /// - `Err(ClingoError)` if a part name contains a null byte or there was an
+    ///   error during the grounding process of the program.
+    pub fn ground(&self, parts: &[(&str, Vec<Symbol>)]) -> Result<(), ClingoError> {
+        let names = parts
+            .iter()
+            .map(|(name, _)| CString::new(*name))
+            .collect::<Result<Vec<CString>, _>>()?;
+
+        let c_parts = parts
+            .iter()
+            .zip(&names)
+            .map(|((_, params), name)| clingo_part {
+                name: name.as_ptr(),
+                params: params.as_ptr() as *const clingo_symbol_t,
+                size: params.len(),
+            })
+            .collect::<Vec<_>>();
 
         let success = unsafe {
             clingo_control_ground(
                 self.inner.as_ptr(),
-                parts.as_ptr(),
-                1,
+                c_parts.as_ptr(),
+                c_parts.len(),
                 None,
                 std::ptr::null_mut(),
             )
         };
 
         if !success {
-            return Err(ClingoError::new_internal(
-                "Failed to ground program".to_owned(),
-            ));
+            return Err(self.error("Failed to ground program".to_owned()));
         }
 
         Ok(())
@@ -123,7 +298,28 @@ impl Control {
     /// # Returns
     /// - `Ok(SolveHandle)` if the solving process was started successfully.
     /// - `Err(ClingoError)` if there was an error during the starting process of the solving.
-    pub fn solve(&self) -> Result<SolveHandle, ClingoError> {
+    pub fn solve(&self) -> Result<SolveHandle<'_>, ClingoError> {
+        self.solve_with_assumptions(&[])
+    }
+
+    /// Starts the solving process under assumptions in asynchronous and yield mode,
+    /// returning a handle to manage the solving process.
+    ///
+    /// Assumptions are program literals that must hold in every model of this solve call only.
+    /// A positive literal forces its atom to be true, a negative literal forces it to be false.
+    /// They do not change the ground program, so different assumptions can be tried
+    /// one after another without grounding again.
+    ///
+    /// # Parameters
+    /// - `assumptions`: The literals assumed to hold, see [`SymbolicAtoms::literal`].
+    ///
+    /// # Returns
+    /// - `Ok(SolveHandle)` if the solving process was started successfully.
+    /// - `Err(ClingoError)` if there was an error during the starting process of the solving.
+    pub fn solve_with_assumptions(
+        &self,
+        assumptions: &[Literal],
+    ) -> Result<SolveHandle<'_>, ClingoError> {
         const ASYNC_YIELD_MODE: u32 = 3;
 
         let mut handle = std::ptr::null_mut();
@@ -131,8 +327,8 @@ impl Control {
             clingo_control_solve(
                 self.inner.as_ptr(),
                 ASYNC_YIELD_MODE,
-                std::ptr::null(),
-                0,
+                assumptions.as_ptr(),
+                assumptions.len(),
                 None,
                 std::ptr::null_mut(),
                 &mut handle,
@@ -140,9 +336,7 @@ impl Control {
         };
 
         if !success {
-            return Err(ClingoError::new_internal(
-                "Failed to start solving".to_owned(),
-            ));
+            return Err(self.error("Failed to start solving".to_owned()));
         }
 
         let inner = NonNull::new(handle).ok_or_else(|| {
@@ -152,6 +346,203 @@ impl Control {
         Ok(SolveHandle::new(inner))
     }
 
+    /// Starts the solving process under assumptions in asynchronous mode,
+    /// reporting the solve events to a callback.
+    ///
+    /// The callback runs in the solver thread and is invoked for every found model,
+    /// for unsatisfiable optimization bounds, for statistics updates and once when the
+    /// search finishes. No polling of the returned handle is required. A panic in the
+    /// callback is caught and reported to clingo as a runtime error, which stops the search.
+    ///
+    /// # Parameters
+    /// - `assumptions`: The literals assumed to hold, see [`SymbolicAtoms::literal`].
+    /// - `callback`: The callback receiving the solve events. Returning `false` stops the search.
+    ///
+    /// # Returns
+    /// - `Ok(SolveHandle)` if the solving process was started successfully.
+    ///   The handle owns the callback and keeps it alive until the search is done.
+    /// - `Err(ClingoError)` if there was an error during the starting process of the solving.
+    pub fn solve_with_callback<F>(
+        &self,
+        assumptions: &[Literal],
+        callback: F,
+    ) -> Result<SolveHandle<'_>, ClingoError>
+    where
+        F: FnMut(SolveEvent<'_>) -> bool + Send + 'static,
+    {
+        const ASYNC_MODE: u32 = 1;
+
+        let mut callback: Box<SolveEventCallback> = Box::new(Box::new(callback));
+        let data = &mut *callback as *mut SolveEventCallback as *mut c_void;
+
+        let mut handle = std::ptr::null_mut();
+        let success = unsafe {
+            clingo_control_solve(
+                self.inner.as_ptr(),
+                ASYNC_MODE,
+                assumptions.as_ptr(),
+                assumptions.len(),
+                Some(solve_event_callback),
+                data,
+                &mut handle,
+            )
+        };
+
+        if !success {
+            return Err(self.error("Failed to start solving".to_owned()));
+        }
+
+        let inner = NonNull::new(handle).ok_or_else(|| {
+            ClingoError::new_internal("Received null pointer for solve handle".to_owned())
+        })?;
+
+        Ok(SolveHandle::with_callback(inner, callback))
+    }
+
+    /// Assigns a truth value to an external atom.
+    ///
+    /// External atoms are declared with `#external` in the program. Their value
+    /// can be changed between solve calls without grounding again.
+    /// Literals that do not belong to an external atom are ignored by clingo.
+    ///
+    /// # Parameters
+    /// - `literal`: The literal of the external atom, see [`SymbolicAtoms::literal`].
+    ///   A negative literal assigns the inverted value.
+    /// - `value`: The truth value to assign.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the value was assigned successfully.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if there was an error during the assignment.
+    pub fn assign_external(&self, literal: Literal, value: TruthValue) -> Result<(), ClingoError> {
+        let success =
+            unsafe { clingo_control_assign_external(self.inner.as_ptr(), literal, value.into()) };
+
+        if !success {
+            return Err(self.error(format!(
+                "Failed to assign {value:?} to external literal {literal}"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Releases an external atom.
+    ///
+    /// A released atom is permanently false and can no longer be assigned.
+    ///
+    /// # Parameters
+    /// - `literal`: The literal of the external atom, see [`SymbolicAtoms::literal`].
+    ///
+    /// # Returns
+    /// - `Ok(())` if the atom was released successfully.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if there was an error during the release.
+    pub fn release_external(&self, literal: Literal) -> Result<(), ClingoError> {
+        let success = unsafe { clingo_control_release_external(self.inner.as_ptr(), literal) };
+
+        if !success {
+            return Err(self.error(format!("Failed to release external literal {literal}")));
+        }
+
+        Ok(())
+    }
+
+    /// Opens the backend to add rules, atoms and minimize statements directly
+    /// to the ground program.
+    ///
+    /// The backend is closed again when it is dropped.
+    ///
+    /// # Returns
+    /// - `Ok(Backend)` if the backend was opened successfully.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if there was an error while opening the backend.
+    pub fn backend(&self) -> Result<Backend<'_>, ClingoError> {
+        let mut backend = std::ptr::null_mut();
+
+        let success = unsafe { clingo_control_backend(self.inner.as_ptr(), &mut backend) };
+        if !success {
+            return Err(self.error("Failed to retrieve backend from control".to_owned()));
+        }
+        let backend = NonNull::new(backend).ok_or_else(|| {
+            ClingoError::new_internal("Received null pointer for backend".to_owned())
+        })?;
+
+        Backend::new(backend).map_err(|e| e.with_messages(self.logger.take()))
+    }
+
+    /// Retrieves the symbolic atoms of the grounded program.
+    ///
+    /// # Returns
+    /// - `Ok(SymbolicAtoms)` if the symbolic atoms were retrieved successfully.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if there was an error during the retrieval process
+    ///   of the symbolic atoms.
+    pub fn symbolic_atoms(&self) -> Result<SymbolicAtoms<'_>, ClingoError> {
+        let mut atoms = std::ptr::null();
+
+        let success = unsafe { clingo_control_symbolic_atoms(self.inner.as_ptr(), &mut atoms) };
+        if !success {
+            return Err(self.error("Failed to retrieve symbolic atoms from control".to_owned()));
+        }
+        let atoms = NonNull::new(atoms as *mut _).ok_or_else(|| {
+            ClingoError::new_internal("Received null pointer for symbolic atoms".to_owned())
+        })?;
+
+        Ok(SymbolicAtoms::new(atoms))
+    }
+
+    /// Retrieves the theory atoms of the grounded program.
+    ///
+    /// # Returns
+    /// - `Ok(TheoryAtoms)` if the theory atoms were retrieved successfully.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if there was an error during the retrieval process
+    ///   of the theory atoms.
+    pub fn theory_atoms(&self) -> Result<TheoryAtoms<'_>, ClingoError> {
+        let mut atoms = std::ptr::null();
+
+        let success = unsafe { clingo_control_theory_atoms(self.inner.as_ptr(), &mut atoms) };
+        if !success {
+            return Err(self.error("Failed to retrieve theory atoms from control".to_owned()));
+        }
+        let atoms = NonNull::new(atoms as *mut _).ok_or_else(|| {
+            ClingoError::new_internal("Received null pointer for theory atoms".to_owned())
+        })?;
+
+        Ok(TheoryAtoms::new(atoms))
+    }
+
+    /// Retrieves the statistics of the last grounding and solving steps.
+    ///
+    /// Only summary statistics are collected unless the control was created
+    /// with the `--stats` argument.
+    ///
+    /// # Returns
+    /// - `Ok(Statistics)` if the statistics were retrieved successfully.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if there was an error during the retrieval process
+    ///   of the statistics, e.g. while a solve call is running.
+    pub fn statistics(&self) -> Result<Statistics<'_>, ClingoError> {
+        let mut stats = std::ptr::null();
+
+        let success = unsafe { clingo_control_statistics(self.inner.as_ptr(), &mut stats) };
+        if !success {
+            return Err(self.error("Failed to retrieve statistics from control".to_owned()));
+        }
+        let stats = NonNull::new(stats as *mut _).ok_or_else(|| {
+            ClingoError::new_internal("Received null pointer for statistics".to_owned())
+        })?;
+
+        Ok(Statistics::new(stats))
+    }
+
     /// Retrieves the configuration associated with the control.
     ///
     /// # Returns
@@ -165,9 +556,7 @@ impl Control {
 
         let success = unsafe { clingo_control_configuration(self.inner.as_ptr(), &mut conf) };
         if !success {
-            return Err(ClingoError::new_internal(
-                "Failed to retrieve configuration from control".to_owned(),
-            ));
+            return Err(self.error("Failed to retrieve configuration from control".to_owned()));
         }
         let conf = NonNull::new(conf).ok_or_else(|| {
             ClingoError::new_internal("Received null pointer for configuration".to_owned())
@@ -175,6 +564,69 @@ impl Control {
 
         Configuration::new(conf)
     }
+
+    /// Takes the messages clingo reported since the last call.
+    ///
+    /// Messages are collected during creation, loading, adding, grounding and solving.
+    /// Failing calls attach them to their error instead. Messages that were never taken
+    /// are printed as warnings when the control is dropped.
+    ///
+    /// # Returns
+    /// - The messages in the order clingo reported them.
+    pub fn messages(&self) -> Vec<LogMessage> {
+        self.logger.take()
+    }
+
+    /// Registers a propagator checking constraints during the search.
+    ///
+    /// The propagator is called from all solver threads concurrently,
+    /// see [`Propagator`] for how to keep per-thread state.
+    ///
+    /// # Parameters
+    /// - `propagator`: The propagator, owned by the control from now on.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the propagator was registered successfully.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the propagator could not be registered.
+    pub fn register_propagator<P: Propagator + 'static>(
+        &mut self,
+        propagator: P,
+    ) -> Result<(), ClingoError> {
+        let mut registration = Box::new(Registration::new(propagator));
+        let data = &mut *registration as *mut Registration<P> as *mut c_void;
+
+        let success = unsafe {
+            clingo_control_register_propagator(
+                self.inner.as_ptr(),
+                &propagator::callbacks::<P>(),
+                data,
+                false,
+            )
+        };
+        if !success {
+            return Err(self.error("Failed to register propagator".to_owned()));
+        }
+
+        self.propagators.push(registration);
+        Ok(())
+    }
+
+    /// Creates an internal error carrying the messages clingo reported before the failure.
+    fn error(&self, message: String) -> ClingoError {
+        ClingoError::new_internal(message).with_messages(self.logger.take())
+    }
+}
+
+impl fmt::Debug for Control {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Control")
+            .field("inner", &self.inner)
+            .field("logger", &self.logger)
+            .field("propagators", &self.propagators.len())
+            .finish()
+    }
 }
 
 impl Drop for Control {
@@ -182,6 +634,7 @@ impl Drop for Control {
         unsafe {
             clingo_control_free(self.inner.as_ptr());
         }
+        self.logger.flush();
     }
 }
 
@@ -190,12 +643,68 @@ impl Drop for Control {
 pub enum SolveEvent<'a> {
     /// A model was found.
     Model(&'a mut Model),
-    /// The problem is unsatisfiable.
+    /// An optimization problem was found unsatisfiable for the current bound.
     Unsat,
     /// Statistics are available.
     Statistics,
-    /// The solving process has finished.
-    Finish,
+    /// The solving process has finished with the given result.
+    Finish(SolveResult),
+}
+
+/// A solve event callback as stored by a [`SolveHandle`].
+pub type SolveEventCallback = Box<dyn FnMut(SolveEvent<'_>) -> bool + Send>;
+
+/// Forwards the solve events of clingo to a [`SolveEventCallback`].
+///
+/// Unknown event types are ignored and the search continues. Panics must not
+/// unwind into clingo, so they are caught and reported as a clingo runtime error.
+unsafe extern "C" fn solve_event_callback(
+    event_type: clingo_solve_event_type_t,
+    event: *mut c_void,
+    data: *mut c_void,
+    goon: *mut bool,
+) -> bool {
+    const MODEL: clingo_solve_event_type_t =
+        clingo_solve_event_type_e_clingo_solve_event_type_model as _;
+    const UNSAT: clingo_solve_event_type_t =
+        clingo_solve_event_type_e_clingo_solve_event_type_unsat as _;
+    const STATISTICS: clingo_solve_event_type_t =
+        clingo_solve_event_type_e_clingo_solve_event_type_statistics as _;
+    const FINISH: clingo_solve_event_type_t =
+        clingo_solve_event_type_e_clingo_solve_event_type_finish as _;
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let callback = unsafe { &mut *(data as *mut SolveEventCallback) };
+
+        match event_type {
+            MODEL => {
+                let mut model = Model::new(NonNull::new(event as *mut clingo_model_t)?);
+                Some(callback(SolveEvent::Model(&mut model)))
+            }
+            UNSAT => Some(callback(SolveEvent::Unsat)),
+            STATISTICS => Some(callback(SolveEvent::Statistics)),
+            FINISH => {
+                let bits = unsafe { *(event as *const clingo_solve_result_bitset_t) };
+                Some(callback(SolveEvent::Finish(
+                    SolveResult::from_bits_truncate(bits),
+                )))
+            }
+            // Newer clingo versions may add event types, they do not affect the search.
+            _ => Some(true),
+        }
+    }));
+
+    let message = match result {
+        Ok(Some(continue_search)) => {
+            unsafe { *goon = continue_search };
+            return true;
+        }
+        Ok(None) => c"Received an invalid solve event",
+        Err(_) => c"Solve event callback panicked",
+    };
+
+    unsafe { clingo_set_error(clingo_error_e_clingo_error_runtime as _, message.as_ptr()) };
+    false
 }
 
 unsafe impl Send for Control {}
@@ -10,6 +10,7 @@ use super::bindings::{
     clingo_error_e_clingo_error_runtime, clingo_error_e_clingo_error_success,
     clingo_error_e_clingo_error_unknown, clingo_error_message,
 };
+use super::logger::LogMessage;
 
 /// Represents error codes returned by the Clingo API.
 #[derive(Debug)]
@@ -63,6 +64,8 @@ pub enum ClingoError {
         internal_code: ErrorCode,
         /// The detailed error message from the Clingo API
         internal_message: String,
+        /// The messages clingo reported through its logger before the error occurred
+        messages: Vec<LogMessage>,
     },
     /// An error in the Rust interface itself
     Bindings {
@@ -74,6 +77,11 @@ pub enum ClingoError {
         /// A descriptive message about the context of the type error
         message: &'static str,
     },
+    /// A command-line option of the clingo executable that the library does not support
+    UnsupportedArgument {
+        /// The rejected argument
+        argument: String,
+    },
 }
 
 impl std::fmt::Display for ClingoError {
@@ -84,14 +92,23 @@ impl std::fmt::Display for ClingoError {
                 message,
                 internal_code,
                 internal_message,
+                messages,
             } => {
                 write!(
                     f,
                     "Internal error: {message} (code: {internal_code:?}): {internal_message}"
-                )
+                )?;
+                for log_message in messages {
+                    write!(f, "\n{log_message}")?;
+                }
+                Ok(())
             }
             ClingoError::Bindings { message } => write!(f, "Bindings error: {message}"),
             ClingoError::TypeError { message } => write!(f, "Type error: {message}"),
+            ClingoError::UnsupportedArgument { argument } => write!(
+                f,
+                "Unsupported argument: '{argument}' is an option of the clingo executable, not of the library"
+            ),
         }
     }
 }
@@ -129,7 +146,24 @@ impl ClingoError {
             message,
             internal_code,
             internal_message,
+            messages: Vec::new(),
+        }
+    }
+
+    /// Attaches the messages clingo reported through its logger to an internal error.
+    ///
+    /// Other kinds of errors are returned unchanged.
+    ///
+    /// # Parameters
+    /// - `log_messages`: The messages reported before the error occurred.
+    ///
+    /// # Returns
+    /// - The error carrying the messages.
+    pub fn with_messages(mut self, log_messages: Vec<LogMessage>) -> Self {
+        if let ClingoError::Internal { messages, .. } = &mut self {
+            messages.extend(log_messages);
         }
+        self
     }
 
     /// Creates a new type error with the provided message.
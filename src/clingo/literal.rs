@@ -0,0 +1,40 @@
+//! This module provides the types used to refer to atoms and literals
+//! of a ground logic program.
+
+use super::bindings::{
+    clingo_atom_t, clingo_literal_t, clingo_truth_value_e_clingo_truth_value_false,
+    clingo_truth_value_e_clingo_truth_value_free, clingo_truth_value_e_clingo_truth_value_true,
+    clingo_truth_value_t,
+};
+
+/// A program atom, a positive integer identifying a ground atom.
+pub type Atom = clingo_atom_t;
+
+/// A program literal, an atom or its negation.
+///
+/// Positive literals refer to an atom being true, negative literals
+/// (the negated atom) refer to an atom being false.
+pub type Literal = clingo_literal_t;
+
+/// The truth value of an atom, e.g. the value assigned to an external atom.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TruthValue {
+    /// The atom is neither true nor false.
+    ///
+    /// For external atoms this means the solver may choose its value freely.
+    Free,
+    /// The atom is true.
+    True,
+    /// The atom is false.
+    False,
+}
+
+impl From<TruthValue> for clingo_truth_value_t {
+    fn from(value: TruthValue) -> Self {
+        (match value {
+            TruthValue::Free => clingo_truth_value_e_clingo_truth_value_free,
+            TruthValue::True => clingo_truth_value_e_clingo_truth_value_true,
+            TruthValue::False => clingo_truth_value_e_clingo_truth_value_false,
+        }) as clingo_truth_value_t
+    }
+}
@@ -0,0 +1,142 @@
+//! This module provides the capture of messages clingo reports while
+//! parsing, grounding and solving.
+//!
+//! Clingo reports warnings, such as atoms that occur in a body but are never defined,
+//! through a logger callback instead of failing. The messages are collected
+//! per control, so they can be attached to errors or forwarded to the app log.
+
+use std::{
+    ffi::{CStr, c_char, c_void},
+    panic::{AssertUnwindSafe, catch_unwind},
+    sync::Mutex,
+};
+
+use super::bindings::{
+    clingo_warning_e_clingo_warning_atom_undefined, clingo_warning_e_clingo_warning_file_included,
+    clingo_warning_e_clingo_warning_global_variable,
+    clingo_warning_e_clingo_warning_operation_undefined,
+    clingo_warning_e_clingo_warning_runtime_error,
+    clingo_warning_e_clingo_warning_variable_unbounded, clingo_warning_t,
+};
+
+/// The kind of a message reported by clingo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WarningCode {
+    /// An undefined arithmetic operation or weight of an aggregate.
+    OperationUndefined,
+    /// A runtime error, reported before the failing call returns.
+    RuntimeError,
+    /// An atom that does not occur in any rule head.
+    AtomUndefined,
+    /// The same file was included multiple times.
+    FileIncluded,
+    /// A CSP variable with an unbounded domain.
+    VariableUnbounded,
+    /// A global variable in a tuple of an aggregate element.
+    GlobalVariable,
+    /// Other kinds of messages.
+    Other,
+}
+
+impl From<clingo_warning_t> for WarningCode {
+    fn from(code: clingo_warning_t) -> Self {
+        const OPERATION_UNDEFINED: clingo_warning_t =
+            clingo_warning_e_clingo_warning_operation_undefined as _;
+        const RUNTIME_ERROR: clingo_warning_t = clingo_warning_e_clingo_warning_runtime_error as _;
+        const ATOM_UNDEFINED: clingo_warning_t =
+            clingo_warning_e_clingo_warning_atom_undefined as _;
+        const FILE_INCLUDED: clingo_warning_t = clingo_warning_e_clingo_warning_file_included as _;
+        const VARIABLE_UNBOUNDED: clingo_warning_t =
+            clingo_warning_e_clingo_warning_variable_unbounded as _;
+        const GLOBAL_VARIABLE: clingo_warning_t =
+            clingo_warning_e_clingo_warning_global_variable as _;
+
+        match code {
+            OPERATION_UNDEFINED => WarningCode::OperationUndefined,
+            RUNTIME_ERROR => WarningCode::RuntimeError,
+            ATOM_UNDEFINED => WarningCode::AtomUndefined,
+            FILE_INCLUDED => WarningCode::FileIncluded,
+            VARIABLE_UNBOUNDED => WarningCode::VariableUnbounded,
+            GLOBAL_VARIABLE => WarningCode::GlobalVariable,
+            _ => WarningCode::Other,
+        }
+    }
+}
+
+/// A single message reported by clingo.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogMessage {
+    /// The kind of the message.
+    pub code: WarningCode,
+    /// The message text, including the location in the program if available.
+    pub message: String,
+}
+
+impl std::fmt::Display for LogMessage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{:?}] {}", self.code, self.message)
+    }
+}
+
+/// Collects the messages clingo reports for a control.
+#[derive(Debug, Default)]
+pub struct Logger {
+    messages: Mutex<Vec<LogMessage>>,
+}
+
+impl Logger {
+    /// Takes all messages collected since the last call.
+    ///
+    /// # Returns
+    /// - The collected messages in the order clingo reported them.
+    pub fn take(&self) -> Vec<LogMessage> {
+        match self.messages.lock() {
+            Ok(mut messages) => std::mem::take(&mut *messages),
+            Err(poisoned) => std::mem::take(&mut *poisoned.into_inner()),
+        }
+    }
+
+    /// Prints all collected messages as warnings.
+    ///
+    /// Used for messages nobody took before the control was dropped.
+    pub fn flush(&self) {
+        for message in self.take() {
+            println!("Warning: Clingo reported {message}");
+        }
+    }
+
+    /// Stores a message reported by clingo.
+    fn push(&self, message: LogMessage) {
+        match self.messages.lock() {
+            Ok(mut messages) => messages.push(message),
+            Err(poisoned) => poisoned.into_inner().push(message),
+        }
+    }
+}
+
+/// Receives the messages of clingo and stores them in the [`Logger`] passed as `data`.
+///
+/// # Safety
+/// `data` has to point to a `Logger` that outlives the control the callback is registered for.
+pub unsafe extern "C" fn logger_callback(
+    code: clingo_warning_t,
+    message: *const c_char,
+    data: *mut c_void,
+) {
+    // Panics must not unwind into clingo, a message that cannot be stored is dropped.
+    let _ = catch_unwind(AssertUnwindSafe(|| {
+        let logger = unsafe { &*(data as *const Logger) };
+        let message = if message.is_null() {
+            String::new()
+        } else {
+            unsafe { CStr::from_ptr(message) }
+                .to_string_lossy()
+                .into_owned()
+        };
+
+        logger.push(LogMessage {
+            code: WarningCode::from(code),
+            message,
+        });
+    }));
+}
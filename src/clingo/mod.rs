@@ -1,11 +1,19 @@
 //! This module provides safe Rust interfaces for interacting with
 //! the Clingo API.
 
+pub mod backend;
 pub mod bindings;
 pub mod configuration;
 pub mod control;
 pub mod error;
+pub mod literal;
+pub mod logger;
 pub mod model;
+pub mod propagator;
+pub mod signature;
 pub mod solve_handle;
 pub mod solve_result;
+pub mod statistics;
 pub mod symbol;
+pub mod symbolic_atoms;
+pub mod theory_atoms;
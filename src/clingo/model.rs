@@ -3,8 +3,9 @@
 
 use super::{
     bindings::{
-        clingo_model_number, clingo_model_symbols, clingo_model_symbols_size, clingo_model_t,
-        clingo_symbol_t,
+        clingo_model_cost, clingo_model_cost_size, clingo_model_number,
+        clingo_model_optimality_proven, clingo_model_priority, clingo_model_symbols,
+        clingo_model_symbols_size, clingo_model_t, clingo_symbol_t, clingo_weight_t,
     },
     error::ClingoError,
     symbol::Symbol,
@@ -90,4 +91,97 @@ impl Model {
 
         Ok(symbols)
     }
+
+    /// Retrieves the cost vector of the model.
+    ///
+    /// The vector holds one entry per optimization level, ordered from the highest
+    /// to the lowest priority. Maximize statements are reported as negated costs.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<i64>)` containing the costs of the model, empty if the program has no
+    ///   optimization statements.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if there was an error during the retrieval process
+    ///   of the costs.
+    pub fn cost(&self) -> Result<Vec<i64>, ClingoError> {
+        let size = self.cost_size()?;
+        if size == 0 {
+            return Ok(vec![]);
+        }
+        let mut costs = vec![0; size];
+
+        let success = unsafe { clingo_model_cost(self.0.as_ptr(), costs.as_mut_ptr(), size) };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_model_cost() failed".to_owned(),
+            ));
+        }
+
+        Ok(costs)
+    }
+
+    /// Retrieves the priorities of the optimization levels of the model.
+    ///
+    /// The priorities are in the same order as the entries of [`Model::cost`].
+    ///
+    /// # Returns
+    /// - `Ok(Vec<i32>)` containing the priority of each cost entry.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if there was an error during the retrieval process
+    ///   of the priorities.
+    pub fn priority(&self) -> Result<Vec<i32>, ClingoError> {
+        let size = self.cost_size()?;
+        if size == 0 {
+            return Ok(vec![]);
+        }
+        let mut priorities: Vec<clingo_weight_t> = vec![0; size];
+
+        let success =
+            unsafe { clingo_model_priority(self.0.as_ptr(), priorities.as_mut_ptr(), size) };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_model_priority() failed".to_owned(),
+            ));
+        }
+
+        Ok(priorities)
+    }
+
+    /// Checks whether the model is proven to be optimal.
+    ///
+    /// # Returns
+    /// - `Ok(true)` if no better model exists, `Ok(false)` if the model is only
+    ///   the best one found so far.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if there was an error during the retrieval process
+    ///   of the optimality.
+    pub fn optimality_proven(&self) -> Result<bool, ClingoError> {
+        let mut proven = false;
+
+        let success = unsafe { clingo_model_optimality_proven(self.0.as_ptr(), &mut proven) };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_model_optimality_proven() failed".to_owned(),
+            ));
+        }
+
+        Ok(proven)
+    }
+
+    /// Retrieves the number of optimization levels of the model.
+    fn cost_size(&self) -> Result<usize, ClingoError> {
+        let mut size: usize = 0;
+
+        let success = unsafe { clingo_model_cost_size(self.0.as_ptr(), &mut size) };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_model_cost_size() failed".to_owned(),
+            ));
+        }
+
+        Ok(size)
+    }
 }
@@ -0,0 +1,786 @@
+//! This module provides a safe Rust interface for implementing
+//! clingo propagators.
+//!
+//! A propagator checks constraints in Rust while the solver searches, which is useful
+//! for rules that are awkward to express in ASP. It watches solver literals and is
+//! notified when they are assigned, and it may add clauses to reject assignments.
+//!
+//! # Threading
+//! Clingo may solve with several threads at once, each running its own search.
+//! [`Propagator::propagate`], [`Propagator::undo`] and [`Propagator::check`] are
+//! called concurrently from all of these threads, which is why they take `&self`
+//! and propagators must be `Sync`. State that changes during the search has to be
+//! kept per solver thread, e.g. in a `Vec<Mutex<State>>` indexed by
+//! [`PropagateControl::thread_id`] and sized in [`Propagator::init`] using
+//! [`PropagateInit::number_of_threads`]. Initialization is never run concurrently
+//! with the search, so [`Propagator::init`] takes `&mut self`.
+
+use std::{
+    ffi::{CString, c_void},
+    marker::PhantomData,
+    panic::{AssertUnwindSafe, catch_unwind},
+    ptr::NonNull,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use super::{
+    bindings::{
+        clingo_assignment_decision_level, clingo_assignment_has_conflict,
+        clingo_assignment_has_literal, clingo_assignment_is_total, clingo_assignment_t,
+        clingo_assignment_truth_value, clingo_clause_type_e_clingo_clause_type_learnt,
+        clingo_clause_type_e_clingo_clause_type_static,
+        clingo_clause_type_e_clingo_clause_type_volatile,
+        clingo_clause_type_e_clingo_clause_type_volatile_static, clingo_clause_type_t,
+        clingo_error_e_clingo_error_runtime, clingo_id_t, clingo_literal_t,
+        clingo_propagate_control_add_clause, clingo_propagate_control_add_watch,
+        clingo_propagate_control_assignment, clingo_propagate_control_has_watch,
+        clingo_propagate_control_propagate, clingo_propagate_control_remove_watch,
+        clingo_propagate_control_t, clingo_propagate_control_thread_id,
+        clingo_propagate_init_add_clause, clingo_propagate_init_add_watch,
+        clingo_propagate_init_add_watch_to_thread, clingo_propagate_init_assignment,
+        clingo_propagate_init_number_of_threads, clingo_propagate_init_set_check_mode,
+        clingo_propagate_init_solver_literal, clingo_propagate_init_symbolic_atoms,
+        clingo_propagate_init_t, clingo_propagator_check_mode_e_clingo_propagator_check_mode_both,
+        clingo_propagator_check_mode_e_clingo_propagator_check_mode_fixpoint,
+        clingo_propagator_check_mode_e_clingo_propagator_check_mode_none,
+        clingo_propagator_check_mode_e_clingo_propagator_check_mode_total,
+        clingo_propagator_check_mode_t, clingo_propagator_t, clingo_set_error,
+        clingo_symbolic_atoms_t, clingo_truth_value_e_clingo_truth_value_false,
+        clingo_truth_value_e_clingo_truth_value_true, clingo_truth_value_t,
+    },
+    error::ClingoError,
+    literal::{Literal, TruthValue},
+    symbolic_atoms::SymbolicAtoms,
+};
+
+/// A propagator checking constraints during the search.
+///
+/// All methods have default implementations that accept every assignment,
+/// so a propagator only implements the callbacks it needs.
+/// See the module documentation for the threading guarantees.
+pub trait Propagator: Send + Sync {
+    /// Initializes the propagator before solving.
+    ///
+    /// This is the place to map program literals to solver literals and to add watches.
+    /// It is called again before each subsequent solve call.
+    ///
+    /// # Parameters
+    /// - `init`: The initialization interface of the solver.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` to abort solving.
+    fn init(&mut self, init: &mut PropagateInit<'_>) -> Result<(), ClingoError> {
+        let _ = init;
+        Ok(())
+    }
+
+    /// Propagates after watched literals were assigned.
+    ///
+    /// If adding a clause or propagating reports a conflict, the method must return
+    /// immediately without further modifications of the assignment.
+    ///
+    /// # Parameters
+    /// - `control`: The control of the solver thread calling the propagator.
+    /// - `changes`: The watched solver literals that were assigned.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` to abort solving.
+    fn propagate(
+        &self,
+        control: &mut PropagateControl<'_>,
+        changes: &[Literal],
+    ) -> Result<(), ClingoError> {
+        let _ = (control, changes);
+        Ok(())
+    }
+
+    /// Undoes the state of a solver thread after watched literals were unassigned.
+    ///
+    /// A panic leaves the state of the propagator inconsistent, so the search is
+    /// aborted with an error at the next call of [`Propagator::propagate`] or
+    /// [`Propagator::check`].
+    ///
+    /// # Parameters
+    /// - `control`: The control of the solver thread calling the propagator.
+    /// - `changes`: The watched solver literals that were unassigned.
+    fn undo(&self, control: &PropagateControl<'_>, changes: &[Literal]) {
+        let _ = (control, changes);
+    }
+
+    /// Checks an assignment, by default only once it is total.
+    ///
+    /// # Parameters
+    /// - `control`: The control of the solver thread calling the propagator.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` to abort solving.
+    fn check(&self, control: &mut PropagateControl<'_>) -> Result<(), ClingoError> {
+        let _ = control;
+        Ok(())
+    }
+}
+
+/// When the solver calls [`Propagator::check`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckMode {
+    /// The check is never called.
+    None,
+    /// The check is called on total assignments.
+    Total,
+    /// The check is called on propagation fixpoints.
+    Fixpoint,
+    /// The check is called on both fixpoints and total assignments.
+    Both,
+}
+
+impl From<CheckMode> for clingo_propagator_check_mode_t {
+    fn from(value: CheckMode) -> Self {
+        (match value {
+            CheckMode::None => clingo_propagator_check_mode_e_clingo_propagator_check_mode_none,
+            CheckMode::Total => clingo_propagator_check_mode_e_clingo_propagator_check_mode_total,
+            CheckMode::Fixpoint => {
+                clingo_propagator_check_mode_e_clingo_propagator_check_mode_fixpoint
+            }
+            CheckMode::Both => clingo_propagator_check_mode_e_clingo_propagator_check_mode_both,
+        }) as clingo_propagator_check_mode_t
+    }
+}
+
+/// How long a clause added by a propagator is kept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClauseType {
+    /// The clause may be deleted by the solver's clause deletion.
+    Learnt,
+    /// The clause is never deleted.
+    Static,
+    /// The clause is removed when backtracking below the current level or after solving.
+    Volatile,
+    /// The clause is only removed after solving.
+    VolatileStatic,
+}
+
+impl From<ClauseType> for clingo_clause_type_t {
+    fn from(value: ClauseType) -> Self {
+        (match value {
+            ClauseType::Learnt => clingo_clause_type_e_clingo_clause_type_learnt,
+            ClauseType::Static => clingo_clause_type_e_clingo_clause_type_static,
+            ClauseType::Volatile => clingo_clause_type_e_clingo_clause_type_volatile,
+            ClauseType::VolatileStatic => clingo_clause_type_e_clingo_clause_type_volatile_static,
+        }) as clingo_clause_type_t
+    }
+}
+
+/// The interface to initialize a propagator.
+pub struct PropagateInit<'a> {
+    inner: NonNull<clingo_propagate_init_t>,
+    _solve: PhantomData<&'a mut clingo_propagate_init_t>,
+}
+
+impl<'a> PropagateInit<'a> {
+    /// Maps a program literal, e.g. from the symbolic atoms, to a solver literal.
+    ///
+    /// # Parameters
+    /// - `literal`: The program literal.
+    ///
+    /// # Returns
+    /// - `Ok(Literal)` containing the solver literal.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the literal could not be mapped.
+    pub fn solver_literal(&self, literal: Literal) -> Result<Literal, ClingoError> {
+        let mut solver_literal = 0;
+        let success = unsafe {
+            clingo_propagate_init_solver_literal(self.inner.as_ptr(), literal, &mut solver_literal)
+        };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_propagate_init_solver_literal() failed".to_owned(),
+            ));
+        }
+
+        Ok(solver_literal)
+    }
+
+    /// Watches a solver literal in all solver threads.
+    ///
+    /// # Parameters
+    /// - `literal`: The solver literal to watch.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the watch was added successfully.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the watch could not be added.
+    pub fn add_watch(&mut self, literal: Literal) -> Result<(), ClingoError> {
+        let success = unsafe { clingo_propagate_init_add_watch(self.inner.as_ptr(), literal) };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_propagate_init_add_watch() failed".to_owned(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Watches a solver literal in a single solver thread.
+    ///
+    /// # Parameters
+    /// - `literal`: The solver literal to watch.
+    /// - `thread_id`: The ID of the solver thread.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the watch was added successfully.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the watch could not be added.
+    pub fn add_watch_to_thread(
+        &mut self,
+        literal: Literal,
+        thread_id: u32,
+    ) -> Result<(), ClingoError> {
+        let success = unsafe {
+            clingo_propagate_init_add_watch_to_thread(
+                self.inner.as_ptr(),
+                literal,
+                thread_id as clingo_id_t,
+            )
+        };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_propagate_init_add_watch_to_thread() failed".to_owned(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Retrieves the symbolic atoms of the ground program.
+    ///
+    /// # Returns
+    /// - `Ok(SymbolicAtoms)` containing the symbolic atoms.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the symbolic atoms could not be retrieved.
+    pub fn symbolic_atoms(&self) -> Result<SymbolicAtoms<'_>, ClingoError> {
+        let mut atoms: *const clingo_symbolic_atoms_t = std::ptr::null();
+        let success =
+            unsafe { clingo_propagate_init_symbolic_atoms(self.inner.as_ptr(), &mut atoms) };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_propagate_init_symbolic_atoms() failed".to_owned(),
+            ));
+        }
+
+        let inner = NonNull::new(atoms as *mut clingo_symbolic_atoms_t).ok_or_else(|| {
+            ClingoError::new_internal("Received null pointer for symbolic atoms".to_owned())
+        })?;
+        Ok(SymbolicAtoms::new(inner))
+    }
+
+    /// Retrieves the number of solver threads.
+    ///
+    /// Thread IDs passed to the propagator are in the range `0..number_of_threads()`.
+    ///
+    /// # Returns
+    /// - The number of solver threads.
+    pub fn number_of_threads(&self) -> usize {
+        unsafe { clingo_propagate_init_number_of_threads(self.inner.as_ptr()) as usize }
+    }
+
+    /// Sets when the solver calls [`Propagator::check`].
+    ///
+    /// # Parameters
+    /// - `mode`: The check mode, [`CheckMode::Total`] by default.
+    pub fn set_check_mode(&mut self, mode: CheckMode) {
+        unsafe { clingo_propagate_init_set_check_mode(self.inner.as_ptr(), mode.into()) }
+    }
+
+    /// Adds a clause of solver literals that holds in every solver thread.
+    ///
+    /// # Parameters
+    /// - `clause`: The solver literals of the clause, a disjunction.
+    ///
+    /// # Returns
+    /// - `Ok(true)` if the clause was added.
+    /// - `Ok(false)` if the clause led to a conflict, i.e. the problem is unsatisfiable.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the clause could not be added.
+    pub fn add_clause(&mut self, clause: &[Literal]) -> Result<bool, ClingoError> {
+        let mut result = false;
+        let success = unsafe {
+            clingo_propagate_init_add_clause(
+                self.inner.as_ptr(),
+                clause.as_ptr(),
+                clause.len(),
+                &mut result,
+            )
+        };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_propagate_init_add_clause() failed".to_owned(),
+            ));
+        }
+
+        Ok(result)
+    }
+
+    /// Retrieves the top level assignment of the solver.
+    ///
+    /// # Returns
+    /// - The assignment, which is only valid during initialization.
+    pub fn assignment(&self) -> Assignment<'_> {
+        Assignment::new(unsafe { clingo_propagate_init_assignment(self.inner.as_ptr()) })
+    }
+}
+
+/// The interface of a solver thread to a propagator during the search.
+pub struct PropagateControl<'a> {
+    inner: NonNull<clingo_propagate_control_t>,
+    _solve: PhantomData<&'a mut clingo_propagate_control_t>,
+}
+
+impl<'a> PropagateControl<'a> {
+    /// Retrieves the ID of the solver thread, used to index per-thread state.
+    ///
+    /// # Returns
+    /// - The thread ID, in the range `0..number_of_threads()`.
+    pub fn thread_id(&self) -> u32 {
+        unsafe { clingo_propagate_control_thread_id(self.inner.as_ptr()) }
+    }
+
+    /// Retrieves the current assignment of the solver thread.
+    ///
+    /// # Returns
+    /// - The assignment of the solver thread.
+    pub fn assignment(&self) -> Assignment<'_> {
+        Assignment::new(unsafe { clingo_propagate_control_assignment(self.inner.as_ptr()) })
+    }
+
+    /// Adds a clause of solver literals to the solver thread.
+    ///
+    /// # Parameters
+    /// - `clause`: The solver literals of the clause, a disjunction.
+    /// - `clause_type`: How long the clause is kept.
+    ///
+    /// # Returns
+    /// - `Ok(true)` if propagation may continue.
+    /// - `Ok(false)` if the clause led to a conflict, the propagator must return immediately.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the clause could not be added.
+    pub fn add_clause(
+        &mut self,
+        clause: &[Literal],
+        clause_type: ClauseType,
+    ) -> Result<bool, ClingoError> {
+        let mut result = false;
+        let success = unsafe {
+            clingo_propagate_control_add_clause(
+                self.inner.as_ptr(),
+                clause.as_ptr(),
+                clause.len(),
+                clause_type.into(),
+                &mut result,
+            )
+        };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_propagate_control_add_clause() failed".to_owned(),
+            ));
+        }
+
+        Ok(result)
+    }
+
+    /// Propagates the consequences of the clauses added so far.
+    ///
+    /// # Returns
+    /// - `Ok(true)` if propagation may continue.
+    /// - `Ok(false)` if a conflict was found, the propagator must return immediately.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if propagation failed.
+    pub fn propagate(&mut self) -> Result<bool, ClingoError> {
+        let mut result = false;
+        let success =
+            unsafe { clingo_propagate_control_propagate(self.inner.as_ptr(), &mut result) };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_propagate_control_propagate() failed".to_owned(),
+            ));
+        }
+
+        Ok(result)
+    }
+
+    /// Watches a solver literal in this solver thread.
+    ///
+    /// # Parameters
+    /// - `literal`: The solver literal to watch.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the watch was added successfully.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the watch could not be added.
+    pub fn add_watch(&mut self, literal: Literal) -> Result<(), ClingoError> {
+        let success = unsafe { clingo_propagate_control_add_watch(self.inner.as_ptr(), literal) };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_propagate_control_add_watch() failed".to_owned(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Checks whether a solver literal is watched in this solver thread.
+    ///
+    /// # Parameters
+    /// - `literal`: The solver literal.
+    ///
+    /// # Returns
+    /// - `true` if the literal is watched.
+    pub fn has_watch(&self, literal: Literal) -> bool {
+        unsafe { clingo_propagate_control_has_watch(self.inner.as_ptr(), literal) }
+    }
+
+    /// Removes the watch of a solver literal in this solver thread.
+    ///
+    /// # Parameters
+    /// - `literal`: The solver literal.
+    pub fn remove_watch(&mut self, literal: Literal) {
+        unsafe { clingo_propagate_control_remove_watch(self.inner.as_ptr(), literal) }
+    }
+}
+
+/// The assignment of a solver, valid as long as the interface it was retrieved from.
+pub struct Assignment<'a> {
+    inner: *const clingo_assignment_t,
+    _solve: PhantomData<&'a clingo_assignment_t>,
+}
+
+impl<'a> Assignment<'a> {
+    /// Creates a new `Assignment` from a pointer received from clingo.
+    fn new(inner: *const clingo_assignment_t) -> Self {
+        Assignment {
+            inner,
+            _solve: PhantomData,
+        }
+    }
+
+    /// Retrieves the truth value of a solver literal.
+    ///
+    /// # Parameters
+    /// - `literal`: The solver literal.
+    ///
+    /// # Returns
+    /// - `Ok(TruthValue)` containing the value, `Free` if the literal is unassigned.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the literal is not valid for this assignment.
+    pub fn truth_value(&self, literal: Literal) -> Result<TruthValue, ClingoError> {
+        const TRUE: clingo_truth_value_t = clingo_truth_value_e_clingo_truth_value_true as _;
+        const FALSE: clingo_truth_value_t = clingo_truth_value_e_clingo_truth_value_false as _;
+
+        let mut value: clingo_truth_value_t = 0;
+        let success = unsafe { clingo_assignment_truth_value(self.inner, literal, &mut value) };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_assignment_truth_value() failed".to_owned(),
+            ));
+        }
+
+        Ok(match value {
+            TRUE => TruthValue::True,
+            FALSE => TruthValue::False,
+            _ => TruthValue::Free,
+        })
+    }
+
+    /// Checks whether a solver literal is assigned true.
+    ///
+    /// # Parameters
+    /// - `literal`: The solver literal.
+    ///
+    /// # Returns
+    /// - `Ok(true)` if the literal is true.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the literal is not valid for this assignment.
+    pub fn is_true(&self, literal: Literal) -> Result<bool, ClingoError> {
+        Ok(self.truth_value(literal)? == TruthValue::True)
+    }
+
+    /// Checks whether a solver literal is assigned false.
+    ///
+    /// # Parameters
+    /// - `literal`: The solver literal.
+    ///
+    /// # Returns
+    /// - `Ok(true)` if the literal is false.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the literal is not valid for this assignment.
+    pub fn is_false(&self, literal: Literal) -> Result<bool, ClingoError> {
+        Ok(self.truth_value(literal)? == TruthValue::False)
+    }
+
+    /// Checks whether the assignment contains a solver literal.
+    ///
+    /// # Parameters
+    /// - `literal`: The solver literal.
+    ///
+    /// # Returns
+    /// - `true` if the literal is valid for this assignment.
+    pub fn has_literal(&self, literal: Literal) -> bool {
+        unsafe { clingo_assignment_has_literal(self.inner, literal) }
+    }
+
+    /// Retrieves the current decision level.
+    ///
+    /// # Returns
+    /// - The decision level, `0` at the top level.
+    pub fn decision_level(&self) -> u32 {
+        unsafe { clingo_assignment_decision_level(self.inner) }
+    }
+
+    /// Checks whether the assignment is conflicting.
+    ///
+    /// # Returns
+    /// - `true` if the assignment has a conflict.
+    pub fn has_conflict(&self) -> bool {
+        unsafe { clingo_assignment_has_conflict(self.inner) }
+    }
+
+    /// Checks whether all literals are assigned.
+    ///
+    /// # Returns
+    /// - `true` if the assignment is total.
+    pub fn is_total(&self) -> bool {
+        unsafe { clingo_assignment_is_total(self.inner) }
+    }
+}
+
+/// A propagator registered on a control, together with the state of its callbacks.
+pub struct Registration<P> {
+    propagator: P,
+    undo_panicked: AtomicBool,
+}
+
+impl<P: Propagator> Registration<P> {
+    /// Prepares a propagator for registration.
+    ///
+    /// # Parameters
+    /// - `propagator`: The propagator to register.
+    pub fn new(propagator: P) -> Self {
+        Registration {
+            propagator,
+            undo_panicked: AtomicBool::new(false),
+        }
+    }
+
+    /// Fails if the propagator panicked while undoing an assignment.
+    fn check_undo(&self) -> Result<(), ClingoError> {
+        if self.undo_panicked.load(Ordering::Acquire) {
+            return Err(ClingoError::new_internal(
+                "Propagator panicked while undoing an assignment".to_owned(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Creates the clingo callbacks forwarding to a propagator of type `P`.
+///
+/// The data pointer passed alongside the callbacks must point to a [`Registration<P>`]
+/// that outlives the control it is registered on.
+///
+/// # Returns
+/// - The clingo propagator struct, which clingo copies on registration.
+pub fn callbacks<P: Propagator>() -> clingo_propagator_t {
+    clingo_propagator_t {
+        init: Some(init_callback::<P>),
+        propagate: Some(propagate_callback::<P>),
+        undo: Some(undo_callback::<P>),
+        check: Some(check_callback::<P>),
+        decide: None,
+    }
+}
+
+/// Forwards the initialization to [`Propagator::init`].
+///
+/// The propagator initializes its state again, so a panic of an earlier undo is forgotten.
+unsafe extern "C" fn init_callback<P: Propagator>(
+    init: *mut clingo_propagate_init_t,
+    data: *mut c_void,
+) -> bool {
+    report(catch_unwind(AssertUnwindSafe(|| {
+        let registration = unsafe { &mut *(data as *mut Registration<P>) };
+        registration.undo_panicked.store(false, Ordering::Release);
+        let mut init = PropagateInit {
+            inner: NonNull::new(init).ok_or_else(|| {
+                ClingoError::new_internal("Received null pointer for propagate init".to_owned())
+            })?,
+            _solve: PhantomData,
+        };
+        registration.propagator.init(&mut init)
+    })))
+}
+
+/// Forwards the propagation to [`Propagator::propagate`].
+unsafe extern "C" fn propagate_callback<P: Propagator>(
+    control: *mut clingo_propagate_control_t,
+    changes: *const clingo_literal_t,
+    size: usize,
+    data: *mut c_void,
+) -> bool {
+    report(catch_unwind(AssertUnwindSafe(|| {
+        let registration = unsafe { &*(data as *const Registration<P>) };
+        registration.check_undo()?;
+        let mut control = propagate_control(control)?;
+        registration
+            .propagator
+            .propagate(&mut control, unsafe { literals(changes, size) })
+    })))
+}
+
+/// Forwards the undo to [`Propagator::undo`].
+///
+/// Clingo offers no way to report a failure here, so a panic is recorded
+/// and reported by the next propagate or check callback instead.
+unsafe extern "C" fn undo_callback<P: Propagator>(
+    control: *const clingo_propagate_control_t,
+    changes: *const clingo_literal_t,
+    size: usize,
+    data: *mut c_void,
+) {
+    let registration = unsafe { &*(data as *const Registration<P>) };
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        if let Ok(control) = propagate_control(control as *mut _) {
+            registration
+                .propagator
+                .undo(&control, unsafe { literals(changes, size) });
+        }
+    }));
+
+    if result.is_err() {
+        registration.undo_panicked.store(true, Ordering::Release);
+    }
+}
+
+/// Forwards the check to [`Propagator::check`].
+unsafe extern "C" fn check_callback<P: Propagator>(
+    control: *mut clingo_propagate_control_t,
+    data: *mut c_void,
+) -> bool {
+    report(catch_unwind(AssertUnwindSafe(|| {
+        let registration = unsafe { &*(data as *const Registration<P>) };
+        registration.check_undo()?;
+        let mut control = propagate_control(control)?;
+        registration.propagator.check(&mut control)
+    })))
+}
+
+/// Wraps the propagate control pointer received from clingo.
+fn propagate_control<'a>(
+    control: *mut clingo_propagate_control_t,
+) -> Result<PropagateControl<'a>, ClingoError> {
+    Ok(PropagateControl {
+        inner: NonNull::new(control).ok_or_else(|| {
+            ClingoError::new_internal("Received null pointer for propagate control".to_owned())
+        })?,
+        _solve: PhantomData,
+    })
+}
+
+/// Views the literals received from clingo as a slice.
+unsafe fn literals<'a>(changes: *const clingo_literal_t, size: usize) -> &'a [Literal] {
+    if changes.is_null() || size == 0 {
+        return &[];
+    }
+
+    unsafe { std::slice::from_raw_parts(changes, size) }
+}
+
+/// Reports the outcome of a propagator callback to clingo.
+///
+/// # Returns
+/// - `true` if the callback succeeded, otherwise the clingo error is set.
+fn report(result: std::thread::Result<Result<(), ClingoError>>) -> bool {
+    let message = match result {
+        Ok(Ok(())) => return true,
+        Ok(Err(error)) => error.to_string(),
+        Err(_) => "Propagator panicked".to_owned(),
+    };
+
+    let message = CString::new(message).unwrap_or_else(|_| CString::from(c"Propagator failed"));
+    unsafe { clingo_set_error(clingo_error_e_clingo_error_runtime as _, message.as_ptr()) };
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clingo::{control::Control, symbol::Symbol};
+
+    /// Forbids a single atom by adding a clause as soon as it is assigned true.
+    struct Forbid {
+        atom: Symbol,
+        literal: Literal,
+    }
+
+    impl Propagator for Forbid {
+        fn init(&mut self, init: &mut PropagateInit<'_>) -> Result<(), ClingoError> {
+            let literal = init.symbolic_atoms()?.literal(&self.atom)?.ok_or_else(|| {
+                ClingoError::new_internal(format!("Atom '{}' is not grounded", self.atom))
+            })?;
+            self.literal = init.solver_literal(literal)?;
+            init.add_watch(self.literal)
+        }
+
+        fn propagate(
+            &self,
+            control: &mut PropagateControl<'_>,
+            changes: &[Literal],
+        ) -> Result<(), ClingoError> {
+            if changes.contains(&self.literal) {
+                control.add_clause(&[-self.literal], ClauseType::Learnt)?;
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn registered_propagator_rejects_models() -> Result<(), ClingoError> {
+        let mut control = Control::with_args(&["--models=0"])?;
+        control.add("base", &[], "{ a; b }.")?;
+        control.ground(&[("base", vec![])])?;
+        control.register_propagator(Forbid {
+            atom: Symbol::create_id("a", true)?,
+            literal: 0,
+        })?;
+
+        let mut models = Vec::new();
+        let mut handle = control.solve()?;
+        loop {
+            handle.resume()?;
+            let Some(model) = handle.model()? else {
+                break;
+            };
+            let mut symbols = model
+                .symbols(2)?
+                .iter()
+                .map(Symbol::to_string)
+                .collect::<Vec<_>>();
+            symbols.sort();
+            models.push(symbols);
+        }
+        models.sort();
+
+        assert_eq!(models, vec![vec![], vec!["b".to_owned()]]);
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+//! This module provides a safe Rust interface for interacting with
+//! the Clingo signature API.
+//!
+//! A signature identifies a predicate by its name, arity and sign, e.g. `item/3`.
+
+use std::ffi::{CStr, CString};
+
+use super::{
+    bindings::{
+        clingo_signature_arity, clingo_signature_create, clingo_signature_is_positive,
+        clingo_signature_name, clingo_signature_t,
+    },
+    error::ClingoError,
+};
+
+/// The signature of a predicate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Signature(clingo_signature_t);
+
+impl Signature {
+    /// Creates a new signature.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the predicate.
+    /// - `arity`: The number of arguments of the predicate.
+    /// - `positive`: Whether the predicate has no classical negation sign.
+    ///
+    /// # Returns
+    /// - `Ok(Signature)` if the signature was created successfully.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the name contains a null byte or is not a valid identifier.
+    pub fn new(name: &str, arity: u32, positive: bool) -> Result<Self, ClingoError> {
+        let c_name = CString::new(name)?;
+        let mut signature = 0;
+
+        let success =
+            unsafe { clingo_signature_create(c_name.as_ptr(), arity, positive, &mut signature) };
+        if !success {
+            return Err(ClingoError::new_internal(format!(
+                "Failed to create signature '{name}/{arity}'"
+            )));
+        }
+
+        Ok(Signature(signature))
+    }
+
+    /// Wraps a raw clingo signature.
+    ///
+    /// # Parameters
+    /// - `signature`: The raw signature.
+    ///
+    /// # Returns
+    /// - A new `Signature` instance.
+    pub fn from_raw(signature: clingo_signature_t) -> Self {
+        Signature(signature)
+    }
+
+    /// Returns the raw clingo signature.
+    pub fn raw(&self) -> clingo_signature_t {
+        self.0
+    }
+
+    /// Retrieves the name of the predicate.
+    ///
+    /// # Returns
+    /// - `Ok(String)` containing the name.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if clingo returned no name or the name is not valid UTF-8.
+    pub fn name(&self) -> Result<String, ClingoError> {
+        let ptr = unsafe { clingo_signature_name(self.0) };
+        if ptr.is_null() {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_signature_name() returned null".to_owned(),
+            ));
+        }
+
+        unsafe { CStr::from_ptr(ptr) }
+            .to_str()
+            .map(|name| name.to_owned())
+            .map_err(|_| ClingoError::new_type_error("Signature name is not valid UTF-8"))
+    }
+
+    /// Returns the number of arguments of the predicate.
+    pub fn arity(&self) -> u32 {
+        unsafe { clingo_signature_arity(self.0) }
+    }
+
+    /// Returns whether the predicate has no classical negation sign.
+    pub fn is_positive(&self) -> bool {
+        unsafe { clingo_signature_is_positive(self.0) }
+    }
+}
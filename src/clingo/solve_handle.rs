@@ -1,24 +1,36 @@
 //! This module provides a safe Rust interface for interacting with
 //! the Clingo solve handle API.
 
-use std::ptr::NonNull;
+use std::{marker::PhantomData, ptr::NonNull};
 
 use crate::clingo::bindings::{clingo_solve_handle_cancel, clingo_solve_handle_resume};
 
 use super::{
     bindings::{
-        clingo_model_t, clingo_solve_handle_close, clingo_solve_handle_get,
-        clingo_solve_handle_model, clingo_solve_handle_t,
+        clingo_literal_t, clingo_model_t, clingo_solve_handle_close, clingo_solve_handle_core,
+        clingo_solve_handle_get, clingo_solve_handle_model, clingo_solve_handle_t,
     },
+    control::{Control, SolveEventCallback},
     error::ClingoError,
+    literal::Literal,
     model::Model,
     solve_result::SolveResult,
 };
 
 /// A handle for managing solving processes in Clingo.
-pub struct SolveHandle(NonNull<clingo_solve_handle_t>);
+///
+/// If the solve call reports its events to a callback, the handle owns the callback.
+/// It is released after the handle is closed, when clingo no longer calls it.
+///
+/// The handle borrows the control that started the search, so the control
+/// cannot be freed while the search is still running.
+pub struct SolveHandle<'a> {
+    inner: NonNull<clingo_solve_handle_t>,
+    _callback: Option<Box<SolveEventCallback>>,
+    _control: PhantomData<&'a Control>,
+}
 
-impl SolveHandle {
+impl<'a> SolveHandle<'a> {
     /// Creates a new `SolveHandle` from a `NonNull<clingo_solve_handle_t>`.
     ///
     /// # Parameters
@@ -28,12 +40,36 @@ impl SolveHandle {
     /// # Returns
     /// - A new `SolveHandle` instance.
     pub fn new(inner: NonNull<clingo_solve_handle_t>) -> Self {
-        SolveHandle(inner)
+        SolveHandle {
+            inner,
+            _callback: None,
+            _control: PhantomData,
+        }
+    }
+
+    /// Creates a new `SolveHandle` that keeps the event callback of its solve call alive.
+    ///
+    /// # Parameters
+    /// - `inner`: The `NonNull<clingo_solve_handle_t>` to
+    ///   wrap.
+    /// - `callback`: The callback registered for the solve call.
+    ///
+    /// # Returns
+    /// - A new `SolveHandle` instance.
+    pub fn with_callback(
+        inner: NonNull<clingo_solve_handle_t>,
+        callback: Box<SolveEventCallback>,
+    ) -> Self {
+        SolveHandle {
+            inner,
+            _callback: Some(callback),
+            _control: PhantomData,
+        }
     }
 
     /// Resumes the solving process associated with this handle.
     pub fn resume(&mut self) -> Result<(), ClingoError> {
-        let success = unsafe { clingo_solve_handle_resume(self.0.as_ptr()) };
+        let success = unsafe { clingo_solve_handle_resume(self.inner.as_ptr()) };
         if !success {
             return Err(ClingoError::new_internal(
                 "Failed to resume search".to_owned(),
@@ -44,7 +80,7 @@ impl SolveHandle {
 
     /// Cancels the solving process associated with this handle.
     pub fn cancel(&mut self) -> Result<(), ClingoError> {
-        let success = unsafe { clingo_solve_handle_cancel(self.0.as_ptr()) };
+        let success = unsafe { clingo_solve_handle_cancel(self.inner.as_ptr()) };
         if !success {
             return Err(ClingoError::new_internal(
                 "Failed to cancel search".to_owned(),
@@ -63,7 +99,7 @@ impl SolveHandle {
     /// - `Err(ClingoError)` if an error occurs during retrieval.
     pub fn model(&mut self) -> Result<Option<Model>, ClingoError> {
         let mut model_ptr: *const clingo_model_t = std::ptr::null();
-        let success = unsafe { clingo_solve_handle_model(self.0.as_ptr(), &mut model_ptr) };
+        let success = unsafe { clingo_solve_handle_model(self.inner.as_ptr(), &mut model_ptr) };
         if !success {
             return Err(ClingoError::new_internal(
                 "Failed to retrieve model from solve handle".to_owned(),
@@ -92,7 +128,7 @@ impl SolveHandle {
     ///   of the solve result.
     pub fn get(&mut self) -> Result<SolveResult, ClingoError> {
         let mut result_bits = 0;
-        if !unsafe { clingo_solve_handle_get(self.0.as_ptr(), &mut result_bits) } {
+        if !unsafe { clingo_solve_handle_get(self.inner.as_ptr(), &mut result_bits) } {
             return Err(ClingoError::new_internal(
                 "Call to clingo_solve_handle_get() failed".to_owned(),
             ));
@@ -103,6 +139,34 @@ impl SolveHandle {
         })
     }
 
+    /// Retrieves the unsatisfiable core of the solve call.
+    ///
+    /// The core is a subset of the assumptions of the solve call that cannot hold
+    /// together. It is only available after [`SolveHandle::get`] reported an
+    /// unsatisfiable result and is not guaranteed to be minimal.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<Literal>)` containing the assumption literals of the core.
+    ///   An empty core means the program is unsatisfiable without any assumptions.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the core could not be retrieved.
+    pub fn core(&mut self) -> Result<Vec<Literal>, ClingoError> {
+        let mut core: *const clingo_literal_t = std::ptr::null();
+        let mut size = 0;
+        if !unsafe { clingo_solve_handle_core(self.inner.as_ptr(), &mut core, &mut size) } {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_solve_handle_core() failed".to_owned(),
+            ));
+        }
+
+        if core.is_null() || size == 0 {
+            return Ok(Vec::new());
+        }
+
+        Ok(unsafe { std::slice::from_raw_parts(core, size) }.to_vec())
+    }
+
     /// Waits for the specified amount of time to check for the next result.
     ///
     /// # Parameters
@@ -113,18 +177,18 @@ impl SolveHandle {
     pub fn wait(&self, timeout: f64) -> bool {
         let mut result = false;
         unsafe {
-            super::bindings::clingo_solve_handle_wait(self.0.as_ptr(), timeout, &mut result);
+            super::bindings::clingo_solve_handle_wait(self.inner.as_ptr(), timeout, &mut result);
         }
         result
     }
 }
 
-impl Drop for SolveHandle {
+impl Drop for SolveHandle<'_> {
     fn drop(&mut self) {
         unsafe {
-            clingo_solve_handle_close(self.0.as_ptr());
+            clingo_solve_handle_close(self.inner.as_ptr());
         }
     }
 }
 
-unsafe impl Send for SolveHandle {}
+unsafe impl Send for SolveHandle<'_> {}
@@ -10,7 +10,7 @@ use super::bindings::{
 };
 
 bitflags! {
-    #[derive(Debug)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     /// The result of a solve operation.
     pub struct SolveResult: u32 {
         /// The solve operation found a satisfiable solution.
@@ -0,0 +1,216 @@
+//! This module provides a safe Rust interface for interacting with
+//! the Clingo statistics API.
+//!
+//! Clingo stores its statistics as a tree of maps, arrays and numeric values.
+//! The tree is copied into [`StatisticsEntry`] values, so it can outlive the control
+//! and be sent to other threads.
+
+use std::{ffi::CStr, marker::PhantomData, ptr::NonNull};
+
+use super::{
+    bindings::{
+        clingo_statistics_array_at, clingo_statistics_array_size, clingo_statistics_map_at,
+        clingo_statistics_map_size, clingo_statistics_map_subkey_name, clingo_statistics_root,
+        clingo_statistics_t, clingo_statistics_type,
+        clingo_statistics_type_e_clingo_statistics_type_array,
+        clingo_statistics_type_e_clingo_statistics_type_empty,
+        clingo_statistics_type_e_clingo_statistics_type_map,
+        clingo_statistics_type_e_clingo_statistics_type_value, clingo_statistics_type_t,
+        clingo_statistics_value_get,
+    },
+    control::Control,
+    error::ClingoError,
+};
+
+/// A node of the statistics tree.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StatisticsEntry {
+    /// A node without a value.
+    Empty,
+    /// A numeric value.
+    Value(f64),
+    /// An array of entries.
+    Array(Vec<StatisticsEntry>),
+    /// Named entries in the order clingo reports them.
+    Map(Vec<(String, StatisticsEntry)>),
+}
+
+impl StatisticsEntry {
+    /// Looks up an entry by a dot separated path.
+    ///
+    /// Array elements are addressed by their index, e.g. `solving.threads.0`.
+    ///
+    /// # Parameters
+    /// - `path`: The path of the entry, e.g. `summary.times.solve`.
+    ///   An empty path refers to the entry itself.
+    ///
+    /// # Returns
+    /// - `Some(&StatisticsEntry)` if the entry exists.
+    /// - `None` otherwise.
+    pub fn get(&self, path: &str) -> Option<&StatisticsEntry> {
+        if path.is_empty() {
+            return Some(self);
+        }
+
+        path.split('.').try_fold(self, |entry, key| match entry {
+            StatisticsEntry::Map(entries) => entries
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, entry)| entry),
+            StatisticsEntry::Array(entries) => key
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| entries.get(index)),
+            _ => None,
+        })
+    }
+
+    /// Looks up a numeric value by a dot separated path.
+    ///
+    /// # Parameters
+    /// - `path`: The path of the value, e.g. `summary.times.solve`.
+    ///
+    /// # Returns
+    /// - `Some(f64)` if the path refers to a value.
+    /// - `None` otherwise.
+    pub fn value(&self, path: &str) -> Option<f64> {
+        match self.get(path)? {
+            StatisticsEntry::Value(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+/// The statistics of a control.
+///
+/// The statistics borrow the control they were retrieved from
+/// and are only valid until the next solve call.
+pub struct Statistics<'a> {
+    inner: NonNull<clingo_statistics_t>,
+    _control: PhantomData<&'a Control>,
+}
+
+impl<'a> Statistics<'a> {
+    /// Creates a new `Statistics` instance from a non-null pointer.
+    ///
+    /// # Parameters
+    /// - `inner`: A non-null pointer to `clingo_statistics_t`.
+    ///
+    /// # Returns
+    /// - A new `Statistics` instance.
+    pub fn new(inner: NonNull<clingo_statistics_t>) -> Self {
+        Statistics {
+            inner,
+            _control: PhantomData,
+        }
+    }
+
+    /// Copies the whole statistics tree.
+    ///
+    /// # Returns
+    /// - `Ok(StatisticsEntry)` containing the root of the tree.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if there was an error while traversing the tree.
+    pub fn tree(&self) -> Result<StatisticsEntry, ClingoError> {
+        let mut root = 0;
+
+        let success = unsafe { clingo_statistics_root(self.inner.as_ptr(), &mut root) };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_statistics_root() failed".to_owned(),
+            ));
+        }
+
+        self.entry(root)
+    }
+
+    /// Copies the subtree below the given key.
+    fn entry(&self, key: u64) -> Result<StatisticsEntry, ClingoError> {
+        const EMPTY: clingo_statistics_type_t =
+            clingo_statistics_type_e_clingo_statistics_type_empty as _;
+        const VALUE: clingo_statistics_type_t =
+            clingo_statistics_type_e_clingo_statistics_type_value as _;
+        const ARRAY: clingo_statistics_type_t =
+            clingo_statistics_type_e_clingo_statistics_type_array as _;
+        const MAP: clingo_statistics_type_t =
+            clingo_statistics_type_e_clingo_statistics_type_map as _;
+
+        let stats = self.inner.as_ptr();
+
+        let mut entry_type: clingo_statistics_type_t = 0;
+        if !unsafe { clingo_statistics_type(stats, key, &mut entry_type) } {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_statistics_type() failed".to_owned(),
+            ));
+        }
+
+        match entry_type {
+            EMPTY => Ok(StatisticsEntry::Empty),
+            VALUE => {
+                let mut value = 0.0;
+                if !unsafe { clingo_statistics_value_get(stats, key, &mut value) } {
+                    return Err(ClingoError::new_internal(
+                        "Call to clingo_statistics_value_get() failed".to_owned(),
+                    ));
+                }
+                Ok(StatisticsEntry::Value(value))
+            }
+            ARRAY => {
+                let mut size = 0;
+                if !unsafe { clingo_statistics_array_size(stats, key, &mut size) } {
+                    return Err(ClingoError::new_internal(
+                        "Call to clingo_statistics_array_size() failed".to_owned(),
+                    ));
+                }
+
+                let mut entries = Vec::with_capacity(size);
+                for index in 0..size {
+                    let mut subkey = 0;
+                    if !unsafe { clingo_statistics_array_at(stats, key, index, &mut subkey) } {
+                        return Err(ClingoError::new_internal(
+                            "Call to clingo_statistics_array_at() failed".to_owned(),
+                        ));
+                    }
+                    entries.push(self.entry(subkey)?);
+                }
+                Ok(StatisticsEntry::Array(entries))
+            }
+            MAP => {
+                let mut size = 0;
+                if !unsafe { clingo_statistics_map_size(stats, key, &mut size) } {
+                    return Err(ClingoError::new_internal(
+                        "Call to clingo_statistics_map_size() failed".to_owned(),
+                    ));
+                }
+
+                let mut entries = Vec::with_capacity(size);
+                for index in 0..size {
+                    let mut name_ptr = std::ptr::null();
+                    if !unsafe {
+                        clingo_statistics_map_subkey_name(stats, key, index, &mut name_ptr)
+                    } || name_ptr.is_null()
+                    {
+                        return Err(ClingoError::new_internal(
+                            "Call to clingo_statistics_map_subkey_name() failed".to_owned(),
+                        ));
+                    }
+
+                    let mut subkey = 0;
+                    if !unsafe { clingo_statistics_map_at(stats, key, name_ptr, &mut subkey) } {
+                        return Err(ClingoError::new_internal(
+                            "Call to clingo_statistics_map_at() failed".to_owned(),
+                        ));
+                    }
+
+                    let name = unsafe { CStr::from_ptr(name_ptr) }
+                        .to_string_lossy()
+                        .into_owned();
+                    entries.push((name, self.entry(subkey)?));
+                }
+                Ok(StatisticsEntry::Map(entries))
+            }
+            _ => Err(ClingoError::new_type_error("Unknown statistics entry type")),
+        }
+    }
+}
@@ -2,13 +2,20 @@
 //! the Clingo symbol API.
 
 use crate::clingo::bindings::{
-    clingo_symbol_arguments, clingo_symbol_name, clingo_symbol_number, clingo_symbol_type,
+    clingo_parse_term, clingo_symbol_arguments, clingo_symbol_create_function,
+    clingo_symbol_create_id, clingo_symbol_create_infimum, clingo_symbol_create_number,
+    clingo_symbol_create_string, clingo_symbol_create_supremum, clingo_symbol_hash,
+    clingo_symbol_is_equal_to, clingo_symbol_is_less_than, clingo_symbol_is_positive,
+    clingo_symbol_name, clingo_symbol_number, clingo_symbol_string, clingo_symbol_type,
 };
 use crate::clingo::error::ClingoError;
 
 use super::bindings::{clingo_symbol_t, clingo_symbol_to_string, clingo_symbol_to_string_size};
-use std::ffi::CStr;
+use std::cmp::Ordering;
+use std::ffi::{CStr, CString};
 use std::fmt::{Display, Error, Formatter};
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
 
 /// The type of a Clingo symbol.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -27,7 +34,14 @@ pub enum SymbolType {
     Unknown,
 }
 /// A Clingo symbol.
-#[derive(Debug, Clone)]
+///
+/// The struct is transparent, so slices of symbols can be passed
+/// to clingo as arrays of `clingo_symbol_t`.
+///
+/// Equality, ordering and hashing follow clingo's own symbol comparison,
+/// so symbols can be used as keys in maps and sets.
+#[derive(Debug, Clone, Copy)]
+#[repr(transparent)]
 pub struct Symbol(clingo_symbol_t);
 
 impl Symbol {
@@ -42,6 +56,158 @@ impl Symbol {
         Symbol(symbol)
     }
 
+    /// Creates a number symbol.
+    ///
+    /// # Parameters
+    /// - `number`: The value of the number.
+    ///
+    /// # Returns
+    /// - A new number `Symbol`.
+    pub fn create_number(number: i32) -> Self {
+        let mut symbol = 0;
+        unsafe { clingo_symbol_create_number(number, &mut symbol) };
+        Symbol(symbol)
+    }
+
+    /// Creates the infimum symbol `#inf`, the smallest of all symbols.
+    pub fn create_infimum() -> Self {
+        let mut symbol = 0;
+        unsafe { clingo_symbol_create_infimum(&mut symbol) };
+        Symbol(symbol)
+    }
+
+    /// Creates the supremum symbol `#sup`, the largest of all symbols.
+    pub fn create_supremum() -> Self {
+        let mut symbol = 0;
+        unsafe { clingo_symbol_create_supremum(&mut symbol) };
+        Symbol(symbol)
+    }
+
+    /// Creates a string symbol.
+    ///
+    /// # Parameters
+    /// - `string`: The content of the string, without surrounding quotes.
+    ///
+    /// # Returns
+    /// - `Ok(Symbol)` containing the new string symbol.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the string contains a null byte or the symbol could not be created.
+    pub fn create_string(string: &str) -> Result<Self, ClingoError> {
+        let c_string = CString::new(string)?;
+        let mut symbol = 0;
+        if !unsafe { clingo_symbol_create_string(c_string.as_ptr(), &mut symbol) } {
+            return Err(ClingoError::new_internal(
+                "Failed to create string symbol".to_string(),
+            ));
+        }
+        Ok(Symbol(symbol))
+    }
+
+    /// Creates a function symbol.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the function.
+    /// - `arguments`: The arguments of the function.
+    /// - `positive`: Whether the symbol is positive, `false` creates a classically negated symbol.
+    ///
+    /// # Returns
+    /// - `Ok(Symbol)` containing the new function symbol.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the name contains a null byte or the symbol could not be created.
+    pub fn create_function(
+        name: &str,
+        arguments: &[Symbol],
+        positive: bool,
+    ) -> Result<Self, ClingoError> {
+        let c_name = CString::new(name)?;
+        let mut symbol = 0;
+        let success = unsafe {
+            clingo_symbol_create_function(
+                c_name.as_ptr(),
+                arguments.as_ptr() as *const clingo_symbol_t,
+                arguments.len(),
+                positive,
+                &mut symbol,
+            )
+        };
+        if !success {
+            return Err(ClingoError::new_internal(format!(
+                "Failed to create function symbol '{name}'"
+            )));
+        }
+        Ok(Symbol(symbol))
+    }
+
+    /// Creates a constant symbol, a function without arguments such as `head`.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the constant.
+    /// - `positive`: Whether the symbol is positive, `false` creates a classically negated symbol.
+    ///
+    /// # Returns
+    /// - `Ok(Symbol)` containing the new constant symbol.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the name contains a null byte or the symbol could not be created.
+    pub fn create_id(name: &str, positive: bool) -> Result<Self, ClingoError> {
+        let c_name = CString::new(name)?;
+        let mut symbol = 0;
+        if !unsafe { clingo_symbol_create_id(c_name.as_ptr(), positive, &mut symbol) } {
+            return Err(ClingoError::new_internal(format!(
+                "Failed to create constant symbol '{name}'"
+            )));
+        }
+        Ok(Symbol(symbol))
+    }
+
+    /// Creates a tuple symbol, a function with an empty name such as `(1, a)`.
+    ///
+    /// # Parameters
+    /// - `arguments`: The elements of the tuple.
+    ///
+    /// # Returns
+    /// - `Ok(Symbol)` containing the new tuple symbol.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the symbol could not be created.
+    pub fn create_tuple(arguments: &[Symbol]) -> Result<Self, ClingoError> {
+        Self::create_function("", arguments, true)
+    }
+
+    /// Parses a symbol from its textual representation, e.g. `slot_chosen(35, 1234)`.
+    ///
+    /// The text must be a ground term, it is evaluated by clingo,
+    /// so `1+2` is parsed as the number `3`.
+    ///
+    /// # Parameters
+    /// - `text`: The text to parse.
+    ///
+    /// # Returns
+    /// - `Ok(Symbol)` containing the parsed symbol.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the text contains a null byte or is not a valid ground term.
+    pub fn parse(text: &str) -> Result<Self, ClingoError> {
+        let c_text = CString::new(text)?;
+        let mut symbol = 0;
+        let success = unsafe {
+            clingo_parse_term(c_text.as_ptr(), None, std::ptr::null_mut(), 0, &mut symbol)
+        };
+        if !success {
+            return Err(ClingoError::new_internal(format!(
+                "Failed to parse term '{text}'"
+            )));
+        }
+        Ok(Symbol(symbol))
+    }
+
+    /// Returns the raw `clingo_symbol_t` of the symbol.
+    pub fn raw(&self) -> clingo_symbol_t {
+        self.0
+    }
+
     /// Returns the type of the symbol.
     ///
     /// # Returns
@@ -105,6 +271,55 @@ impl Symbol {
         }
     }
 
+    /// Returns the content of the symbol if it is a string.
+    ///
+    /// # Returns
+    /// - `Ok(String)` if the symbol is a string.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the symbol is not a string or if retrieval fails.
+    pub fn string(&self) -> Result<String, ClingoError> {
+        if self.kind() != SymbolType::String {
+            return Err(ClingoError::new_type_error("Symbol is not a string"));
+        }
+        let mut string_ptr = std::ptr::null();
+        unsafe {
+            if !clingo_symbol_string(self.0, &mut string_ptr) {
+                return Err(ClingoError::new_internal(
+                    "Failed to retrieve string".to_string(),
+                ));
+            }
+            if string_ptr.is_null() {
+                return Ok("".into());
+            }
+            Ok(CStr::from_ptr(string_ptr).to_string_lossy().into_owned())
+        }
+    }
+
+    /// Returns whether the symbol is positive if it is a function.
+    ///
+    /// A function symbol is negative if it is classically negated, e.g. `-item(1)`.
+    ///
+    /// # Returns
+    /// - `Ok(bool)` if the symbol is a function.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the symbol is not a function or if retrieval fails.
+    pub fn is_positive(&self) -> Result<bool, ClingoError> {
+        if self.kind() != SymbolType::Function {
+            return Err(ClingoError::new_type_error("Symbol is not a function"));
+        }
+        let mut positive = false;
+        unsafe {
+            if !clingo_symbol_is_positive(self.0, &mut positive) {
+                return Err(ClingoError::new_internal(
+                    "Failed to retrieve sign".to_string(),
+                ));
+            }
+        }
+        Ok(positive)
+    }
+
     /// Returns the arguments of the symbol if it is a function.
     ///
     /// # Returns
@@ -164,3 +379,43 @@ impl Display for Symbol {
         }
     }
 }
+
+impl PartialEq for Symbol {
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { clingo_symbol_is_equal_to(self.0, other.0) }
+    }
+}
+
+impl Eq for Symbol {}
+
+impl PartialOrd for Symbol {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Symbol {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if unsafe { clingo_symbol_is_less_than(self.0, other.0) } {
+            Ordering::Less
+        } else if unsafe { clingo_symbol_is_less_than(other.0, self.0) } {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
+
+impl Hash for Symbol {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        unsafe { clingo_symbol_hash(self.0) }.hash(state);
+    }
+}
+
+impl FromStr for Symbol {
+    type Err = ClingoError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Symbol::parse(text)
+    }
+}
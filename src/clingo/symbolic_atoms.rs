@@ -0,0 +1,362 @@
+//! This module provides a safe Rust interface for interacting with
+//! the Clingo symbolic atoms API.
+//!
+//! Symbolic atoms map the ground atoms of a program, identified by their symbols,
+//! to the literals the solver uses internally. They can be looked up by symbol
+//! or iterated, either all at once or per predicate signature.
+
+use std::{marker::PhantomData, ptr::NonNull};
+
+use super::{
+    bindings::{
+        clingo_signature_t, clingo_symbol_t, clingo_symbolic_atom_iterator_t,
+        clingo_symbolic_atoms_begin, clingo_symbolic_atoms_end, clingo_symbolic_atoms_find,
+        clingo_symbolic_atoms_is_external, clingo_symbolic_atoms_is_fact,
+        clingo_symbolic_atoms_is_valid, clingo_symbolic_atoms_iterator_is_equal_to,
+        clingo_symbolic_atoms_literal, clingo_symbolic_atoms_next,
+        clingo_symbolic_atoms_signatures, clingo_symbolic_atoms_signatures_size,
+        clingo_symbolic_atoms_size, clingo_symbolic_atoms_symbol, clingo_symbolic_atoms_t,
+    },
+    control::Control,
+    error::ClingoError,
+    literal::Literal,
+    signature::Signature,
+    symbol::Symbol,
+};
+
+/// The symbolic atoms of a grounded program.
+///
+/// The symbolic atoms borrow the control they were retrieved from
+/// and are only valid as long as the control is not modified.
+pub struct SymbolicAtoms<'a> {
+    inner: NonNull<clingo_symbolic_atoms_t>,
+    _control: PhantomData<&'a Control>,
+}
+
+impl<'a> SymbolicAtoms<'a> {
+    /// Creates a new `SymbolicAtoms` instance from a non-null pointer.
+    ///
+    /// # Parameters
+    /// - `inner`: A non-null pointer to `clingo_symbolic_atoms_t`.
+    ///
+    /// # Returns
+    /// - A new `SymbolicAtoms` instance.
+    pub fn new(inner: NonNull<clingo_symbolic_atoms_t>) -> Self {
+        SymbolicAtoms {
+            inner,
+            _control: PhantomData,
+        }
+    }
+
+    /// Looks up the program literal of a ground atom.
+    ///
+    /// # Parameters
+    /// - `symbol`: The symbol of the atom, e.g. `slot_chosen(35, 1234)`.
+    ///
+    /// # Returns
+    /// - `Ok(Some(Literal))` if the atom was grounded.
+    /// - `Ok(None)` if the atom does not occur in the ground program.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if there was an error during the lookup.
+    pub fn literal(&self, symbol: &Symbol) -> Result<Option<Literal>, ClingoError> {
+        let Some(iterator) = self.find(symbol)? else {
+            return Ok(None);
+        };
+
+        let mut literal = 0;
+        let success =
+            unsafe { clingo_symbolic_atoms_literal(self.inner.as_ptr(), iterator, &mut literal) };
+        if !success {
+            return Err(ClingoError::new_internal(format!(
+                "Failed to retrieve literal of '{symbol}'"
+            )));
+        }
+
+        Ok(Some(literal))
+    }
+
+    /// Returns an iterator over all atoms of the grounded program.
+    ///
+    /// # Returns
+    /// - `Ok(SymbolicAtomIter)` iterating over all atoms.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the iterator could not be created.
+    pub fn iter(&self) -> Result<SymbolicAtomIter<'a>, ClingoError> {
+        self.iter_from(std::ptr::null())
+    }
+
+    /// Returns an iterator over the atoms of a single predicate.
+    ///
+    /// # Parameters
+    /// - `signature`: The signature of the predicate, e.g. `item/3`.
+    ///
+    /// # Returns
+    /// - `Ok(SymbolicAtomIter)` iterating over the atoms with the given signature.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the iterator could not be created.
+    pub fn iter_signature(
+        &self,
+        signature: &Signature,
+    ) -> Result<SymbolicAtomIter<'a>, ClingoError> {
+        let raw = signature.raw();
+        self.iter_from(&raw)
+    }
+
+    /// Retrieves the number of atoms in the grounded program.
+    ///
+    /// # Returns
+    /// - `Ok(usize)` containing the number of atoms.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if there was an error during the retrieval.
+    pub fn len(&self) -> Result<usize, ClingoError> {
+        let mut size = 0;
+        let success = unsafe { clingo_symbolic_atoms_size(self.inner.as_ptr(), &mut size) };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_symbolic_atoms_size() failed".to_owned(),
+            ));
+        }
+
+        Ok(size)
+    }
+
+    /// Checks whether the grounded program has no atoms.
+    ///
+    /// # Returns
+    /// - `Ok(true)` if there are no atoms.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if there was an error during the retrieval.
+    pub fn is_empty(&self) -> Result<bool, ClingoError> {
+        Ok(self.len()? == 0)
+    }
+
+    /// Retrieves the signatures of all predicates occurring in the grounded program.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<Signature>)` containing the predicate signatures.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if there was an error during the retrieval.
+    pub fn signatures(&self) -> Result<Vec<Signature>, ClingoError> {
+        let mut size = 0;
+        let success =
+            unsafe { clingo_symbolic_atoms_signatures_size(self.inner.as_ptr(), &mut size) };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_symbolic_atoms_signatures_size() failed".to_owned(),
+            ));
+        }
+        if size == 0 {
+            return Ok(vec![]);
+        }
+
+        let mut signatures: Vec<clingo_signature_t> = vec![0; size];
+        let success = unsafe {
+            clingo_symbolic_atoms_signatures(self.inner.as_ptr(), signatures.as_mut_ptr(), size)
+        };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_symbolic_atoms_signatures() failed".to_owned(),
+            ));
+        }
+
+        Ok(signatures.into_iter().map(Signature::from_raw).collect())
+    }
+
+    /// Creates an iterator starting at the first atom with the given signature,
+    /// or at the first atom of the program if the signature is null.
+    fn iter_from(
+        &self,
+        signature: *const clingo_signature_t,
+    ) -> Result<SymbolicAtomIter<'a>, ClingoError> {
+        let mut current = 0;
+        let success =
+            unsafe { clingo_symbolic_atoms_begin(self.inner.as_ptr(), signature, &mut current) };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_symbolic_atoms_begin() failed".to_owned(),
+            ));
+        }
+
+        let mut end = 0;
+        let success = unsafe { clingo_symbolic_atoms_end(self.inner.as_ptr(), &mut end) };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_symbolic_atoms_end() failed".to_owned(),
+            ));
+        }
+
+        Ok(SymbolicAtomIter {
+            atoms: self.inner,
+            current,
+            end,
+            _control: PhantomData,
+        })
+    }
+
+    /// Finds the iterator pointing to the atom with the given symbol.
+    fn find(
+        &self,
+        symbol: &Symbol,
+    ) -> Result<Option<clingo_symbolic_atom_iterator_t>, ClingoError> {
+        let mut iterator = 0;
+        let success =
+            unsafe { clingo_symbolic_atoms_find(self.inner.as_ptr(), symbol.raw(), &mut iterator) };
+        if !success {
+            return Err(ClingoError::new_internal(format!(
+                "Failed to find symbolic atom '{symbol}'"
+            )));
+        }
+
+        let mut valid = false;
+        let success =
+            unsafe { clingo_symbolic_atoms_is_valid(self.inner.as_ptr(), iterator, &mut valid) };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Failed to check symbolic atom iterator".to_owned(),
+            ));
+        }
+
+        Ok(valid.then_some(iterator))
+    }
+}
+
+/// A single atom of the grounded program.
+#[derive(Debug, Clone, Copy)]
+pub struct SymbolicAtom<'a> {
+    atoms: NonNull<clingo_symbolic_atoms_t>,
+    iterator: clingo_symbolic_atom_iterator_t,
+    _control: PhantomData<&'a Control>,
+}
+
+impl SymbolicAtom<'_> {
+    /// Retrieves the symbol of the atom.
+    ///
+    /// # Returns
+    /// - `Ok(Symbol)` containing the symbol, e.g. `item(1234, 35, "Ring")`.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if there was an error during the retrieval.
+    pub fn symbol(&self) -> Result<Symbol, ClingoError> {
+        let mut symbol: clingo_symbol_t = 0;
+        let success = unsafe {
+            clingo_symbolic_atoms_symbol(self.atoms.as_ptr(), self.iterator, &mut symbol)
+        };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_symbolic_atoms_symbol() failed".to_owned(),
+            ));
+        }
+
+        Ok(Symbol::new(symbol))
+    }
+
+    /// Retrieves the program literal of the atom.
+    ///
+    /// # Returns
+    /// - `Ok(Literal)` containing the literal.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if there was an error during the retrieval.
+    pub fn literal(&self) -> Result<Literal, ClingoError> {
+        let mut literal = 0;
+        let success = unsafe {
+            clingo_symbolic_atoms_literal(self.atoms.as_ptr(), self.iterator, &mut literal)
+        };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_symbolic_atoms_literal() failed".to_owned(),
+            ));
+        }
+
+        Ok(literal)
+    }
+
+    /// Checks whether the atom is a fact.
+    ///
+    /// # Returns
+    /// - `Ok(true)` if the atom is true in every model.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if there was an error during the check.
+    pub fn is_fact(&self) -> Result<bool, ClingoError> {
+        let mut fact = false;
+        let success =
+            unsafe { clingo_symbolic_atoms_is_fact(self.atoms.as_ptr(), self.iterator, &mut fact) };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_symbolic_atoms_is_fact() failed".to_owned(),
+            ));
+        }
+
+        Ok(fact)
+    }
+
+    /// Checks whether the atom is declared as external.
+    ///
+    /// # Returns
+    /// - `Ok(true)` if the atom was declared with `#external` and not released.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if there was an error during the check.
+    pub fn is_external(&self) -> Result<bool, ClingoError> {
+        let mut external = false;
+        let success = unsafe {
+            clingo_symbolic_atoms_is_external(self.atoms.as_ptr(), self.iterator, &mut external)
+        };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_symbolic_atoms_is_external() failed".to_owned(),
+            ));
+        }
+
+        Ok(external)
+    }
+}
+
+/// An iterator over the atoms of a grounded program.
+///
+/// The iteration stops early if clingo fails to advance the iterator.
+pub struct SymbolicAtomIter<'a> {
+    atoms: NonNull<clingo_symbolic_atoms_t>,
+    current: clingo_symbolic_atom_iterator_t,
+    end: clingo_symbolic_atom_iterator_t,
+    _control: PhantomData<&'a Control>,
+}
+
+impl<'a> Iterator for SymbolicAtomIter<'a> {
+    type Item = SymbolicAtom<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut at_end = true;
+        let success = unsafe {
+            clingo_symbolic_atoms_iterator_is_equal_to(
+                self.atoms.as_ptr(),
+                self.current,
+                self.end,
+                &mut at_end,
+            )
+        };
+        if !success || at_end {
+            return None;
+        }
+
+        let atom = SymbolicAtom {
+            atoms: self.atoms,
+            iterator: self.current,
+            _control: PhantomData,
+        };
+
+        let mut next = 0;
+        let success =
+            unsafe { clingo_symbolic_atoms_next(self.atoms.as_ptr(), self.current, &mut next) };
+        self.current = if success { next } else { self.end };
+
+        Some(atom)
+    }
+}
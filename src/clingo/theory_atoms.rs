@@ -0,0 +1,111 @@
+//! This module provides a safe Rust interface for inspecting
+//! the theory atoms of a grounded program.
+//!
+//! Theory atoms are the ground instances of `&name { ... }` atoms. The encoding
+//! currently uses none, so they are mainly useful to confirm that.
+
+use std::{ffi::CStr, marker::PhantomData, ptr::NonNull};
+
+use super::{
+    bindings::{
+        clingo_id_t, clingo_theory_atoms_atom_to_string, clingo_theory_atoms_atom_to_string_size,
+        clingo_theory_atoms_size, clingo_theory_atoms_t,
+    },
+    control::Control,
+    error::ClingoError,
+};
+
+/// The theory atoms of a grounded program.
+///
+/// The theory atoms borrow the control they were retrieved from
+/// and are only valid as long as the control is not modified.
+pub struct TheoryAtoms<'a> {
+    inner: NonNull<clingo_theory_atoms_t>,
+    _control: PhantomData<&'a Control>,
+}
+
+impl<'a> TheoryAtoms<'a> {
+    /// Creates a new `TheoryAtoms` instance from a non-null pointer.
+    ///
+    /// # Parameters
+    /// - `inner`: A non-null pointer to `clingo_theory_atoms_t`.
+    ///
+    /// # Returns
+    /// - A new `TheoryAtoms` instance.
+    pub fn new(inner: NonNull<clingo_theory_atoms_t>) -> Self {
+        TheoryAtoms {
+            inner,
+            _control: PhantomData,
+        }
+    }
+
+    /// Retrieves the number of theory atoms.
+    ///
+    /// # Returns
+    /// - `Ok(usize)` containing the number of theory atoms.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if there was an error during the retrieval.
+    pub fn len(&self) -> Result<usize, ClingoError> {
+        let mut size = 0;
+        let success = unsafe { clingo_theory_atoms_size(self.inner.as_ptr(), &mut size) };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_theory_atoms_size() failed".to_owned(),
+            ));
+        }
+
+        Ok(size)
+    }
+
+    /// Checks whether the grounded program has no theory atoms.
+    ///
+    /// # Returns
+    /// - `Ok(true)` if there are no theory atoms.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if there was an error during the retrieval.
+    pub fn is_empty(&self) -> Result<bool, ClingoError> {
+        Ok(self.len()? == 0)
+    }
+
+    /// Renders all theory atoms as they would appear in the program.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<String>)` containing one string per theory atom.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if an atom could not be rendered.
+    pub fn to_strings(&self) -> Result<Vec<String>, ClingoError> {
+        (0..self.len()?)
+            .map(|atom| self.atom_to_string(atom as clingo_id_t))
+            .collect()
+    }
+
+    /// Renders a single theory atom.
+    fn atom_to_string(&self, atom: clingo_id_t) -> Result<String, ClingoError> {
+        let mut size = 0;
+        let success = unsafe {
+            clingo_theory_atoms_atom_to_string_size(self.inner.as_ptr(), atom, &mut size)
+        };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_theory_atoms_atom_to_string_size() failed".to_owned(),
+            ));
+        }
+
+        let mut buffer = vec![0; size];
+        let success = unsafe {
+            clingo_theory_atoms_atom_to_string(self.inner.as_ptr(), atom, buffer.as_mut_ptr(), size)
+        };
+        if !success {
+            return Err(ClingoError::new_internal(
+                "Call to clingo_theory_atoms_atom_to_string() failed".to_owned(),
+            ));
+        }
+
+        Ok(unsafe { CStr::from_ptr(buffer.as_ptr()) }
+            .to_string_lossy()
+            .into_owned())
+    }
+}
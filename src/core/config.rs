@@ -15,6 +15,10 @@ pub struct Config {
     /// The data configuration section.
     #[serde(rename = "data")]
     pub data: DataConfig,
+
+    /// The optimization configuration section.
+    #[serde(rename = "optimization", default)]
+    pub optimization: OptimizationConfig,
 }
 
 /// This struct represents the database configuration section of the application.
@@ -31,15 +35,97 @@ pub struct DataConfig {
     pub items_path: String,
 }
 
+/// This struct represents the optimization configuration section of the application.
+///
+/// The whole section is optional, missing values fall back to their defaults.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct OptimizationConfig {
+    /// Additional clingo command-line arguments, e.g. `["--opt-mode=optN", "-t", "4"]`.
+    ///
+    /// A `--time-limit` is moved to `time_limit_secs` when the configuration is loaded.
+    /// Other options of the clingo executable, such as `--outf`, are not supported.
+    pub clingo_args: Vec<String>,
+
+    /// If set, the generated problem instance is additionally written to this path.
+    ///
+    /// This is meant for debugging the instance generation and the encoding,
+    /// the solver itself never reads this file.
+    pub instance_debug_path: Option<String>,
+
+    /// If set, the encoding is read from this path instead of the one built into the binary.
+    pub encoding_path: Option<String>,
+
+    /// The number of solver threads running clingo's parallel portfolio.
+    ///
+    /// If set, this takes precedence over a `-t` or `--parallel-mode` given in
+    /// `clingo_args`, and `0` uses one thread per available core. If unset, the
+    /// parallel mode of `clingo_args` is used, or a single thread if none is given.
+    pub threads: Option<usize>,
+
+    /// The time budget of a single solve call in seconds.
+    ///
+    /// When it is used up, the search stops and the best model found so far is kept.
+    pub time_limit_secs: Option<u64>,
+
+    /// The number of models in a row without improvement after which the search stops.
+    ///
+    /// Under clingo's default optimization mode every model improves the cost, so this
+    /// only takes effect when enumerating models, e.g. with `--opt-mode=optN`.
+    /// Use `max_unimproved_secs` to stop a search that no longer improves.
+    pub max_unimproved_models: Option<u32>,
+
+    /// The number of seconds without an improving model after which the search stops.
+    ///
+    /// Counts from the start of the solve call until the first model is found.
+    pub max_unimproved_secs: Option<u64>,
+}
+
+impl OptimizationConfig {
+    /// Moves a `--time-limit` given in `clingo_args` to `time_limit_secs`.
+    ///
+    /// The time limit is an option of the clingo executable, which the clingo library
+    /// rejects. An explicitly set `time_limit_secs` takes precedence.
+    ///
+    /// # Returns
+    /// An error if the time limit is missing or not a number of seconds.
+    fn extract_time_limit(&mut self) -> Result<(), Box<dyn Error>> {
+        let mut args = Vec::new();
+        let mut remaining = std::mem::take(&mut self.clingo_args).into_iter();
+
+        while let Some(arg) = remaining.next() {
+            let value = if arg == "--time-limit" {
+                remaining.next()
+            } else if let Some(value) = arg.strip_prefix("--time-limit=") {
+                Some(value.to_owned())
+            } else {
+                args.push(arg);
+                continue;
+            };
+
+            let value = value.ok_or("Missing value for --time-limit in clingo_args")?;
+            let secs = value.parse::<u64>().map_err(|_| {
+                format!("Invalid --time-limit '{value}' in clingo_args, expected seconds")
+            })?;
+            self.time_limit_secs.get_or_insert(secs);
+        }
+
+        self.clingo_args = args;
+        Ok(())
+    }
+}
+
 /// Loads the configuration from a TOML file at the specified path.
 ///
 /// # Parameters
 /// - `path`: The path to the configuration file.
 ///
 /// # Returns
-/// A `Result` containing the parsed `Config` or an error if the file could not be read or parsed.
+/// A `Result` containing the parsed `Config` or an error if the file could not be read or parsed,
+/// or if `clingo_args` contain an invalid `--time-limit`.
 pub fn load_config(path: &str) -> Result<Config, Box<dyn Error>> {
     let config_str = std::fs::read_to_string(path)?;
-    let config: Config = toml::from_str(&config_str)?;
+    let mut config: Config = toml::from_str(&config_str)?;
+    config.optimization.extract_time_limit()?;
     Ok(config)
 }
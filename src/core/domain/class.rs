@@ -5,9 +5,11 @@
 
 use std::collections::HashSet;
 
-use strum::{Display, EnumIter, FromRepr};
+use strum::{Display, EnumIter, FromRepr, IntoEnumIterator};
 
-use crate::core::domain::{item_type::ItemType, realm::Realm, stat::Stat};
+use crate::core::domain::{
+    item_type::ItemType, realm::Realm, stat::Stat, stat_category::StatCategory,
+};
 
 /// Representation of a character class in Dark Age of Camelot.
 ///
@@ -282,6 +284,58 @@ impl Class {
         }
     }
 
+    /// Returns the stats that matter for the class, in the order of [`Stat`].
+    ///
+    /// These are all physical stats and resists, the acuity stat of the class
+    /// and its skill lines.
+    ///
+    /// # Examples
+    /// ```
+    /// use templess::core::domain::class::Class;
+    /// use templess::core::domain::stat::Stat;
+    /// let stats = Class::Bard.relevant_stats();
+    /// assert!(stats.contains(&Stat::Strength));
+    /// assert!(stats.contains(&Stat::Charisma));
+    /// assert!(stats.contains(&Stat::Music));
+    /// assert!(!stats.contains(&Stat::Piety));
+    /// ```
+    pub fn relevant_stats(&self) -> Vec<Stat> {
+        let skill_lines = self.skill_lines();
+        let acuity_stat = self.acuity_stat();
+
+        Stat::iter()
+            .filter(|stat| match stat.category() {
+                StatCategory::PhysicalStats | StatCategory::Resists => true,
+                StatCategory::AcuityStats => Some(*stat) == acuity_stat,
+                _ => skill_lines.contains(stat),
+            })
+            .collect()
+    }
+
+    /// Returns whether the class can wield a weapon in the left hand.
+    ///
+    /// All other classes can only use the left hand for a shield.
+    ///
+    /// # Examples
+    /// ```
+    /// use templess::core::domain::class::Class;
+    /// assert!(Class::Blademaster.can_dual_wield());
+    /// assert!(!Class::Bard.can_dual_wield());
+    /// ```
+    pub fn can_dual_wield(&self) -> bool {
+        matches!(
+            self,
+            Class::Infiltrator
+                | Class::Mercenary
+                | Class::Shadowblade
+                | Class::Berserker
+                | Class::Savage
+                | Class::Blademaster
+                | Class::Nightshade
+                | Class::Ranger
+        )
+    }
+
     /// Returns the allowed item types for the class.
     ///
     /// # Examples
@@ -6,6 +6,7 @@
 //! As that it is the most likely to be changed as requirements evolve.
 
 use crate::core::domain::item_type::ItemType;
+use std::collections::HashSet;
 
 use super::{class::Class, item_bonus::ItemBonus, item_slot::ItemSlot, realm::Realm};
 
@@ -96,3 +97,56 @@ pub struct Item {
     /// JSON representation of the second reactive effect, if any.
     pub react2_json: Option<String>,
 }
+
+/// The `weapon_hand` value of weapons that are wielded with both hands.
+const WEAPON_HAND_TWO_HANDED: u16 = 1;
+
+/// The `weapon_hand` value of weapons that can be wielded in the left hand.
+const WEAPON_HAND_LEFT: u16 = 2;
+
+impl Item {
+    /// Returns the weapon slots a class can equip the item in.
+    ///
+    /// Shields only fit the left hand and instruments the ranged slot, if the class
+    /// can use them. Left-hand weapons fit the left hand only for classes that can
+    /// dual wield, otherwise they are wielded in the right hand.
+    ///
+    /// # Parameters
+    /// - `class`: The class equipping the item.
+    ///
+    /// # Returns
+    /// - The weapon slots of the item, empty if the item is not a weapon, shield or instrument.
+    pub fn weapon_slots(&self, class: Class) -> HashSet<ItemSlot> {
+        let mut slots = HashSet::new();
+        if !class.allowed_item_types().contains(&self.object_type) {
+            return slots;
+        }
+
+        match self.object_type {
+            ItemType::Shield => {
+                slots.insert(ItemSlot::LeftHand);
+            }
+            ItemType::Instrument => {
+                slots.insert(ItemSlot::Ranged);
+            }
+            item_type if item_type.is_ranged_weapon() => {
+                slots.insert(ItemSlot::Ranged);
+            }
+            item_type if item_type.is_melee_weapon() => match self.weapon_hand {
+                WEAPON_HAND_TWO_HANDED => {
+                    slots.insert(ItemSlot::TwoHanded);
+                }
+                WEAPON_HAND_LEFT if class.can_dual_wield() => {
+                    slots.insert(ItemSlot::RightHand);
+                    slots.insert(ItemSlot::LeftHand);
+                }
+                _ => {
+                    slots.insert(ItemSlot::RightHand);
+                }
+            },
+            _ => {}
+        }
+
+        slots
+    }
+}
@@ -136,4 +136,38 @@ impl ItemType {
     pub fn id(&self) -> u16 {
         *self as u16
     }
+
+    /// Checks whether the item type is a ranged weapon, i.e. a bow or crossbow.
+    ///
+    /// # Examples
+    /// ```
+    /// use templess::core::domain::item_type::ItemType;
+    /// assert!(ItemType::RecurveBow.is_ranged_weapon());
+    /// assert!(!ItemType::Blade.is_ranged_weapon());
+    /// ```
+    pub fn is_ranged_weapon(&self) -> bool {
+        matches!(
+            self,
+            ItemType::ShortBow
+                | ItemType::LongBow
+                | ItemType::Crossbow
+                | ItemType::CompositeBow
+                | ItemType::RecurveBow
+        )
+    }
+
+    /// Checks whether the item type is a melee or casting weapon.
+    ///
+    /// # Examples
+    /// ```
+    /// use templess::core::domain::item_type::ItemType;
+    /// assert!(ItemType::Blunt.is_melee_weapon());
+    /// assert!(ItemType::Staff.is_melee_weapon());
+    /// assert!(!ItemType::Shield.is_melee_weapon());
+    /// ```
+    pub fn is_melee_weapon(&self) -> bool {
+        let id = self.id();
+        (ItemType::Crush.id()..=ItemType::MaulerStaff.id()).contains(&id)
+            && !self.is_ranged_weapon()
+    }
 }
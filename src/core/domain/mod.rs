@@ -5,7 +5,10 @@ pub mod item;
 pub mod item_bonus;
 pub mod item_slot;
 pub mod item_type;
+pub mod priority_profile;
 pub mod realm;
 pub mod stat;
 pub mod stat_category;
+pub mod stat_requirement;
 pub mod template;
+pub mod weight_profile;
@@ -0,0 +1,108 @@
+//! This module defines the priority profile of a template.
+//!
+//! Priorities turn the optimization into a lexicographic one: the utility of a higher
+//! priority level is maximized first, lower levels only decide between templates that are
+//! equally good on all higher levels. By default all stats share a single level.
+
+use std::collections::HashMap;
+
+use strum::{Display, EnumIter};
+
+use crate::core::domain::{stat::Stat, stat_category::StatCategory};
+
+/// Represents a group of stats that share a priority level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumIter)]
+pub enum PriorityGroup {
+    /// Resistances.
+    Resists,
+
+    /// Physical and acuity stats including their cap increases.
+    Stats,
+
+    /// All skill lines.
+    Skills,
+
+    /// Trials of Atlantis bonuses.
+    #[strum(to_string = "ToA Bonuses")]
+    ToaBonuses,
+
+    /// Stats that do not fit into the other groups, such as armor factor.
+    Other,
+}
+
+impl PriorityGroup {
+    /// Returns the priority group of a stat.
+    ///
+    /// # Parameters
+    /// - `stat`: The stat to get the group for.
+    pub fn of(stat: Stat) -> Self {
+        match stat.category() {
+            StatCategory::PhysicalStats
+            | StatCategory::AcuityStats
+            | StatCategory::PhysicalStatCaps
+            | StatCategory::AcuityStatCaps => PriorityGroup::Stats,
+            StatCategory::Resists => PriorityGroup::Resists,
+            StatCategory::MagicSkills
+            | StatCategory::MeleeSkills
+            | StatCategory::ArcherySkills
+            | StatCategory::DualWieldingSkills
+            | StatCategory::OtherSkills => PriorityGroup::Skills,
+            StatCategory::ToaBonuses => PriorityGroup::ToaBonuses,
+            StatCategory::OtherStats => PriorityGroup::Other,
+        }
+    }
+}
+
+/// Represents the priority levels of the stat groups of a template.
+///
+/// Higher levels are optimized first. To cap all resists before anything else,
+/// the resists get the highest level.
+///
+/// # Examples
+/// ```
+/// use templess::core::domain::priority_profile::{PriorityGroup, PriorityProfile};
+/// use templess::core::domain::stat::Stat;
+/// let mut profile = PriorityProfile::default();
+/// assert_eq!(profile.level(PriorityGroup::Resists), PriorityProfile::DEFAULT_LEVEL);
+///
+/// profile.set_level(PriorityGroup::Resists, 3);
+/// assert_eq!(profile.level_of(Stat::HeatResist), 3);
+/// assert_eq!(profile.level_of(Stat::Strength), PriorityProfile::DEFAULT_LEVEL);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PriorityProfile {
+    levels: HashMap<PriorityGroup, u16>,
+}
+
+impl PriorityProfile {
+    /// The level of groups without a custom priority.
+    pub const DEFAULT_LEVEL: u16 = 0;
+
+    /// Returns the priority level of a group.
+    ///
+    /// # Parameters
+    /// - `group`: The group to get the level for.
+    pub fn level(&self, group: PriorityGroup) -> u16 {
+        self.levels
+            .get(&group)
+            .copied()
+            .unwrap_or(Self::DEFAULT_LEVEL)
+    }
+
+    /// Returns the priority level of the group a stat belongs to.
+    ///
+    /// # Parameters
+    /// - `stat`: The stat to get the level for.
+    pub fn level_of(&self, stat: Stat) -> u16 {
+        self.level(PriorityGroup::of(stat))
+    }
+
+    /// Sets the priority level of a group.
+    ///
+    /// # Parameters
+    /// - `group`: The group to set the level for.
+    /// - `level`: The priority level, higher levels are optimized first.
+    pub fn set_level(&mut self, group: PriorityGroup, level: u16) {
+        self.levels.insert(group, level);
+    }
+}
@@ -0,0 +1,103 @@
+//! This module defines the stat requirements of a template.
+//!
+//! Unlike weights, requirements are hard constraints: templates that violate
+//! a requirement are rejected by the optimization, not just rated lower.
+
+use std::fmt::{self, Display};
+
+use crate::core::domain::stat::Stat;
+
+/// Represents a hard requirement on the total of a stat.
+///
+/// # Examples
+/// ```
+/// use templess::core::domain::stat::Stat;
+/// use templess::core::domain::stat_requirement::StatRequirement;
+/// let requirement = StatRequirement::AtLeast(Stat::Strength, 100);
+/// assert_eq!(requirement.stat(), Stat::Strength);
+/// assert_eq!(requirement.to_string(), "strength >= 100");
+/// assert_eq!(StatRequirement::Capped(Stat::Constitution).to_string(), "constitution at cap");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatRequirement {
+    /// The total of the stat must be at least the given value.
+    ///
+    /// Points above the cap do not count, so the value is compared against the capped
+    /// total. For stats with a cap stat, this includes the cap increase.
+    AtLeast(Stat, u16),
+
+    /// The total of the stat must reach its cap, including cap increases.
+    Capped(Stat),
+}
+
+impl StatRequirement {
+    /// Creates a minimum requirement that the stat can reach.
+    ///
+    /// Values above the highest total of the stat, see [`StatRequirement::highest_total`],
+    /// could never be satisfied and are clamped to it.
+    ///
+    /// # Parameters
+    /// - `stat`: The stat the requirement applies to.
+    /// - `value`: The minimum total of the stat.
+    ///
+    /// # Examples
+    /// ```
+    /// use templess::core::domain::stat::Stat;
+    /// use templess::core::domain::stat_requirement::StatRequirement;
+    /// assert_eq!(
+    ///     StatRequirement::at_least(Stat::HeatResist, 100),
+    ///     StatRequirement::AtLeast(Stat::HeatResist, Stat::HeatResist.cap())
+    /// );
+    /// ```
+    pub fn at_least(stat: Stat, value: u16) -> Self {
+        StatRequirement::AtLeast(stat, value.min(Self::highest_total(stat)))
+    }
+
+    /// Returns the highest total a stat can reach.
+    ///
+    /// This is the cap of the stat, increased by the cap of its cap stat if it has one.
+    ///
+    /// # Parameters
+    /// - `stat`: The stat to get the highest total for.
+    pub fn highest_total(stat: Stat) -> u16 {
+        stat.cap() + stat.cap_stat().map_or(0, |cap_stat| cap_stat.cap())
+    }
+
+    /// Returns the stat the requirement applies to.
+    pub fn stat(&self) -> Stat {
+        match self {
+            StatRequirement::AtLeast(stat, _) | StatRequirement::Capped(stat) => *stat,
+        }
+    }
+}
+
+impl Display for StatRequirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StatRequirement::AtLeast(stat, value) => write!(f, "{stat} >= {value}"),
+            StatRequirement::Capped(stat) => write!(f, "{stat} at cap"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn at_least_clamps_to_the_capped_total() {
+        let cap = Stat::Constitution.cap() + Stat::ConstitutionCap.cap();
+        assert_eq!(
+            StatRequirement::at_least(Stat::Constitution, 150),
+            StatRequirement::AtLeast(Stat::Constitution, cap)
+        );
+        assert_eq!(
+            StatRequirement::at_least(Stat::Constitution, 50),
+            StatRequirement::AtLeast(Stat::Constitution, 50)
+        );
+        assert_eq!(
+            StatRequirement::at_least(Stat::ConstitutionCap, u16::MAX),
+            StatRequirement::AtLeast(Stat::ConstitutionCap, Stat::ConstitutionCap.cap())
+        );
+    }
+}
@@ -2,7 +2,10 @@
 
 use std::{collections::HashMap, sync::Arc};
 
-use crate::core::domain::{class::Class, item::Item, item_slot::ItemSlot};
+use crate::core::domain::{
+    class::Class, item::Item, item_slot::ItemSlot, priority_profile::PriorityProfile,
+    stat_requirement::StatRequirement, weight_profile::WeightProfile,
+};
 
 /// Represents a Dark Age of Camelot template.
 ///
@@ -22,6 +25,15 @@ pub struct Template {
 
     /// The slots and their associated items.
     pub slots: HashMap<ItemSlot, Arc<Item>>,
+
+    /// The utility weights per stat point the template is optimized for.
+    pub weights: WeightProfile,
+
+    /// The priority levels of the stat groups the template is optimized for.
+    pub priorities: PriorityProfile,
+
+    /// The stat requirements every optimized template has to meet.
+    pub requirements: Vec<StatRequirement>,
 }
 
 impl Template {
@@ -35,6 +47,9 @@ impl Template {
             name: "Untitled Template".to_string(),
             class,
             slots: HashMap::new(),
+            weights: WeightProfile::default(),
+            priorities: PriorityProfile::default(),
+            requirements: Vec::new(),
         }
     }
 
@@ -0,0 +1,95 @@
+//! This module defines the weight profile of a template.
+//!
+//! The weight of a stat is the utility of a single stat point for the optimization.
+//! Stats without a custom weight fall back to their default utility, see [`Stat::utility_per_point`].
+
+use std::collections::HashMap;
+
+use crate::core::domain::stat::Stat;
+
+/// Represents the utility weights per stat point of a template.
+///
+/// A tank may value constitution and resists higher than a caster, who in turn
+/// prefers acuity and skill points.
+///
+/// # Examples
+/// ```
+/// use templess::core::domain::stat::Stat;
+/// use templess::core::domain::weight_profile::WeightProfile;
+/// let mut profile = WeightProfile::default();
+/// assert_eq!(profile.weight(Stat::Constitution), Stat::Constitution.utility_per_point());
+///
+/// assert!(profile.set_weight(Stat::Constitution, 2.0));
+/// assert_eq!(profile.weight(Stat::Constitution), 2.0);
+/// assert!(profile.is_custom(Stat::Constitution));
+///
+/// assert!(!profile.set_weight(Stat::Strength, -1.0));
+/// assert!(!profile.set_weight(Stat::Strength, f32::NAN));
+/// assert!(!profile.set_weight(Stat::Strength, WeightProfile::MAX_WEIGHT * 2.0));
+/// assert!(!profile.is_custom(Stat::Strength));
+///
+/// profile.reset(Stat::Constitution);
+/// assert!(!profile.is_custom(Stat::Constitution));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WeightProfile {
+    weights: HashMap<Stat, f32>,
+}
+
+impl WeightProfile {
+    /// The highest weight of a stat.
+    ///
+    /// The weights are scaled to integers in the problem instance and multiplied with the
+    /// stat totals by the encoding, so larger weights could overflow the integers of the grounder.
+    pub const MAX_WEIGHT: f32 = 100.0;
+
+    /// Returns the weight of a stat.
+    ///
+    /// # Parameters
+    /// - `stat`: The stat to get the weight for.
+    ///
+    /// # Returns
+    /// The custom weight of the stat, or its default utility if none is set.
+    pub fn weight(&self, stat: Stat) -> f32 {
+        self.weights
+            .get(&stat)
+            .copied()
+            .unwrap_or_else(|| stat.utility_per_point())
+    }
+
+    /// Sets a custom weight for a stat.
+    ///
+    /// Weights that are not finite, negative or above [`WeightProfile::MAX_WEIGHT`]
+    /// are rejected and leave the profile unchanged.
+    ///
+    /// # Parameters
+    /// - `stat`: The stat to set the weight for.
+    /// - `weight`: The utility of a single point of the stat.
+    ///
+    /// # Returns
+    /// `true` if the weight was valid and set, `false` otherwise.
+    pub fn set_weight(&mut self, stat: Stat, weight: f32) -> bool {
+        if !(0.0..=Self::MAX_WEIGHT).contains(&weight) {
+            return false;
+        }
+
+        self.weights.insert(stat, weight);
+        true
+    }
+
+    /// Resets a stat to its default weight.
+    ///
+    /// # Parameters
+    /// - `stat`: The stat to reset.
+    pub fn reset(&mut self, stat: Stat) {
+        self.weights.remove(&stat);
+    }
+
+    /// Checks whether a stat has a custom weight.
+    ///
+    /// # Parameters
+    /// - `stat`: The stat to check.
+    pub fn is_custom(&self, stat: Stat) -> bool {
+        self.weights.contains_key(&stat)
+    }
+}
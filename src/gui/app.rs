@@ -1,5 +1,6 @@
 //! This module defines the main application component of the GUI.
 
+use std::collections::HashSet;
 use std::path::Path;
 use std::sync::{Arc, Mutex};
 
@@ -39,6 +40,12 @@ fn App() -> Element {
         db_connection: db_connection.clone(),
         template: Arc::new(Mutex::new(None)),
         items: Arc::new(Mutex::new(Vec::new())),
+        excluded_items: Arc::new(Mutex::new(HashSet::new())),
+        session: Arc::new(Mutex::new(None)),
+        instance_summary: Arc::new(Mutex::new(None)),
+        statistics: Arc::new(Mutex::new(None)),
+        unsat_core: Arc::new(Mutex::new(None)),
+        clingo_messages: Arc::new(Mutex::new(Vec::new())),
     };
 
     use_context_provider(|| Signal::new(app_state));
@@ -0,0 +1,55 @@
+//! This module defines the conflict panel component for the dashboard.
+
+use dioxus::prelude::*;
+
+use crate::app_state::AppState;
+use crate::optimization::assumption::explain_core;
+
+/// The ConflictPanel component explains why the last optimization run found no template.
+///
+/// It lists the pinned items and stat requirements of the unsatisfiable core
+/// reported by the solver, which cannot all hold at the same time.
+#[component]
+pub fn ConflictPanel() -> Element {
+    let app_state = use_context::<Signal<AppState>>();
+
+    let report = use_memo(move || {
+        let state = app_state.read();
+        let core = state
+            .unsat_core
+            .lock()
+            .ok()
+            .and_then(|guard| guard.clone())?;
+        let items = state
+            .items
+            .lock()
+            .map(|guard| guard.clone())
+            .unwrap_or_default();
+
+        let conflicts = core
+            .iter()
+            .map(|assumption| assumption.describe(&items))
+            .collect::<Vec<_>>();
+        Some((explain_core(&core, &items), conflicts))
+    });
+
+    let Some((explanation, conflicts)) = report() else {
+        return rsx! {};
+    };
+
+    rsx! {
+        div { class: "w-full max-w-[1600px] mx-auto px-4",
+            div { class: "bg-card border border-error rounded-lg p-4 shadow-sm w-full",
+                h3 { class: "text-error font-bold border-b border-border pb-2 mb-3",
+                    "No Valid Template"
+                }
+                p { class: "text-sm text-foreground/90 mb-2", "{explanation}" }
+                ul { class: "list-disc list-inside text-xs text-foreground-secondary",
+                    {conflicts.into_iter().map(|conflict| rsx! {
+                        li { "{conflict}" }
+                    })}
+                }
+            }
+        }
+    }
+}
@@ -4,7 +4,10 @@
 use dioxus::prelude::*;
 
 use crate::gui::dashboard::{
-    attributes::attribute_overview::AttributeOverview, inventory::Inventory,
+    attributes::attribute_overview::AttributeOverview, conflict_panel::ConflictPanel,
+    instance_panel::InstancePanel, inventory::Inventory, message_panel::MessagePanel,
+    priority_editor::PriorityEditor, requirement_editor::RequirementEditor,
+    statistics_panel::StatisticsPanel, weight_editor::WeightEditor,
 };
 
 /// The main page the users can interact with when they opened a template.
@@ -17,7 +20,14 @@ pub fn DashboardPage() -> Element {
     rsx! {
         div { class: "flex flex-col gap-8 border-border mx-auto",
             Inventory {}
+            ConflictPanel {}
+            MessagePanel {}
             AttributeOverview {}
+            WeightEditor {}
+            PriorityEditor {}
+            RequirementEditor {}
+            StatisticsPanel {}
+            InstancePanel {}
         }
     }
 }
@@ -0,0 +1,41 @@
+//! This module defines the frame shared by the template editors of the dashboard.
+
+use dioxus::prelude::*;
+
+/// The EditorPanel component frames a template editor.
+///
+/// It shows the title together with a reset and an apply button above the editor content.
+/// The editors keep their changes in a draft, see
+/// [`use_template_draft`](crate::gui::dashboard::template_draft::use_template_draft),
+/// which is applied at once.
+#[component]
+pub fn EditorPanel(
+    title: String,
+    reset_label: String,
+    on_reset: EventHandler<MouseEvent>,
+    on_apply: EventHandler<MouseEvent>,
+    children: Element,
+) -> Element {
+    rsx! {
+        div { class: "w-full max-w-[1600px] mx-auto px-4",
+            div { class: "bg-card border border-border rounded-lg p-4 shadow-sm w-full",
+                div { class: "flex items-center justify-between border-b border-border pb-2 mb-3",
+                    h3 { class: "text-accent font-bold", "{title}" }
+                    div { class: "flex gap-2",
+                        button {
+                            class: "text-xs px-3 py-1 rounded border border-border text-foreground-secondary hover:bg-foreground/10 cursor-pointer",
+                            onclick: move |event| on_reset.call(event),
+                            "{reset_label}"
+                        }
+                        button {
+                            class: "text-xs px-3 py-1 rounded border border-accent text-accent bg-accent/20 hover:bg-accent/40 cursor-pointer",
+                            onclick: move |event| on_apply.call(event),
+                            "Apply"
+                        }
+                    }
+                }
+                div { class: "grid grid-cols-2 md:grid-cols-5 gap-x-6 gap-y-2", {children} }
+            }
+        }
+    }
+}
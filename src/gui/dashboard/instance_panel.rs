@@ -0,0 +1,74 @@
+//! This module defines the instance panel component for the dashboard.
+
+use dioxus::prelude::*;
+
+use crate::app_state::AppState;
+
+/// The InstancePanel component displays a debugging view of the grounded problem instance.
+///
+/// It shows how many item atoms were grounded and whether they match the items
+/// the instance was generated from.
+#[component]
+pub fn InstancePanel() -> Element {
+    let app_state = use_context::<Signal<AppState>>();
+
+    let summary = use_memo(move || {
+        let state = app_state.read();
+        state
+            .instance_summary
+            .lock()
+            .ok()
+            .and_then(|guard| guard.clone())
+    });
+
+    let Some(summary) = summary() else {
+        return rsx! {};
+    };
+
+    let rows = [
+        ("Items", summary.expected_items.to_string()),
+        ("item/3", summary.item_atoms.to_string()),
+        ("item_bonus/3", summary.item_bonus_atoms.to_string()),
+        (
+            "External available/1",
+            summary.available_externals.to_string(),
+        ),
+        ("Theory atoms", summary.theory_atoms.to_string()),
+    ];
+
+    let (match_text, match_color) = if summary.matches_items() {
+        ("Instance matches the item list".to_owned(), "text-success")
+    } else {
+        (
+            format!(
+                "Missing items: {:?}, unexpected items: {:?}",
+                summary.missing_items, summary.unexpected_items
+            ),
+            "text-error",
+        )
+    };
+
+    rsx! {
+        div { class: "w-full max-w-[1600px] mx-auto pb-10 px-4",
+            div { class: "bg-card border border-border rounded-lg p-4 shadow-sm w-full",
+                h3 { class: "text-accent font-bold border-b border-border pb-2 mb-3",
+                    "Grounded Instance"
+                }
+                div { class: "grid grid-cols-2 md:grid-cols-5 gap-x-6 gap-y-1",
+                    {
+                        rows.into_iter()
+                            .map(|(label, value)| {
+                                rsx! {
+                                    div { class: "flex flex-col text-xs",
+                                        span { class: "text-foreground/90 font-medium", "{label}" }
+                                        span { class: "text-foreground-secondary font-mono", "{value}" }
+                                    }
+                                }
+                            })
+                    }
+                }
+                p { class: "text-xs mt-3 {match_color}", "{match_text}" }
+            }
+        }
+    }
+}
@@ -2,8 +2,6 @@
 
 use dioxus::prelude::*;
 use dioxus_free_icons::{Icon, icons::ld_icons::LdPlus};
-use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, Ordering};
 use std::vec;
 use tokio::sync::mpsc::unbounded_channel;
 
@@ -13,7 +11,8 @@ use crate::gui::dashboard::{
     circle::{Circle, Point},
     inventory_slot::InventorySlot,
 };
-use crate::optimization::worker::{OptimizeStatus, start_optimization_worker};
+use crate::optimization::session::{SessionCommand, start_optimization_session};
+use crate::optimization::worker::{OptimizeStatus, Solution, SolveLimit};
 
 #[derive(PartialEq, Clone)]
 enum UiState {
@@ -30,11 +29,189 @@ enum UiState {
 ///
 /// Thus each inventory, a set of items associated with a character class, represents
 /// a specific problem instance of the optimization problem this application is trying to solve.
+///
+/// The inventory starts the optimization session of the template when it is mounted
+/// and shuts it down again when it is dropped.
 #[component]
 pub fn Inventory() -> Element {
     let mut app_state = use_context::<Signal<AppState>>();
-    let mut ui_state = use_signal(|| UiState::Idle);
-    let mut stop_flag = use_signal::<Option<Arc<AtomicBool>>>(|| None);
+    let mut ui_state = use_signal(|| UiState::Preparing);
+    let mut model_quality = use_signal::<Option<(Vec<(i32, i64)>, bool)>>(|| None);
+    let mut outcome = use_signal::<Option<(String, &'static str)>>(|| None);
+
+    use_hook(move || {
+        let state = app_state.read().clone();
+
+        let template = match state.template.lock() {
+            Ok(guard) => match guard.clone() {
+                Some(tmp) => tmp,
+                None => {
+                    println!("Optimization session not started: No active template.");
+                    return;
+                }
+            },
+            Err(_) => {
+                println!("Warning: Template mutex was poisoned.");
+                return;
+            }
+        };
+
+        let items = match state.items.lock() {
+            Ok(guard) => guard.clone(),
+            Err(_) => {
+                println!("Warning: Items mutex was poisoned.");
+                return;
+            }
+        };
+
+        let excluded_items = match state.excluded_items.lock() {
+            Ok(guard) => guard.clone(),
+            Err(_) => {
+                println!("Warning: Excluded items mutex was poisoned.");
+                return;
+            }
+        };
+
+        let (tx, mut rx) = unbounded_channel();
+
+        let mut show_best = move |best: Option<Solution>| {
+            if let Some(best) = best {
+                if let Ok(mut guard) = app_state.write().template.lock() {
+                    *guard = Some(best.template);
+                }
+                model_quality.set(Some((best.levels(), best.optimal)));
+            }
+        };
+
+        spawn(async move {
+            while let Some(msg) = rx.recv().await {
+                match msg {
+                    OptimizeStatus::Setup => {
+                        ui_state.set(UiState::Preparing);
+                        if let Ok(mut guard) = app_state.write().clingo_messages.lock() {
+                            guard.clear();
+                        }
+                    }
+                    OptimizeStatus::Grounding => {
+                        ui_state.set(UiState::Preparing);
+                    }
+                    OptimizeStatus::Instance(summary) => {
+                        if !summary.matches_items() {
+                            println!(
+                                "Warning: Grounded items do not match the item list, missing {:?}, unexpected {:?}",
+                                summary.missing_items, summary.unexpected_items
+                            );
+                        }
+                        if let Ok(mut guard) = app_state.write().instance_summary.lock() {
+                            *guard = Some(summary);
+                        }
+                    }
+                    OptimizeStatus::Ready => {
+                        ui_state.set(UiState::Idle);
+                    }
+                    OptimizeStatus::Solving => {
+                        ui_state.set(UiState::Solving);
+                        model_quality.set(None);
+                        outcome.set(None);
+                        if let Ok(mut guard) = app_state.write().unsat_core.lock() {
+                            *guard = None;
+                        }
+                    }
+                    OptimizeStatus::NewModel(solution) => {
+                        if let Ok(mut guard) = app_state.write().template.lock() {
+                            *guard = Some(solution.template);
+                        }
+                        model_quality.set(Some((solution.levels(), solution.optimal)));
+                    }
+                    OptimizeStatus::Statistics(statistics) => {
+                        if let Ok(mut guard) = app_state.write().statistics.lock() {
+                            *guard = Some(statistics);
+                        }
+                    }
+                    OptimizeStatus::Messages(messages) => {
+                        if let Ok(mut guard) = app_state.write().clingo_messages.lock() {
+                            guard.extend(messages);
+                        }
+                    }
+                    OptimizeStatus::Exhausted { best, .. } => {
+                        ui_state.set(UiState::Idle);
+                        let text = if best.is_some() {
+                            "Search exhausted - proven optimal"
+                        } else {
+                            "Search exhausted without a model"
+                        };
+                        show_best(best);
+                        outcome.set(Some((text.to_owned(), "text-success")));
+                    }
+                    OptimizeStatus::Unsatisfiable { best, core, .. } => {
+                        ui_state.set(UiState::Idle);
+                        show_best(best);
+                        if let Ok(mut guard) = app_state.write().unsat_core.lock() {
+                            *guard = Some(core);
+                        }
+                        outcome.set(Some((
+                            "No valid template exists for the pinned items and requirements"
+                                .to_owned(),
+                            "text-error",
+                        )));
+                    }
+                    OptimizeStatus::Cancelled { best, .. } => {
+                        ui_state.set(UiState::Idle);
+                        let text = if best.is_some() {
+                            "Stopped - not proven optimal"
+                        } else {
+                            "Stopped before a model was found"
+                        };
+                        show_best(best);
+                        outcome.set(Some((text.to_owned(), "text-warning")));
+                    }
+                    OptimizeStatus::TimedOut { limit, best, .. } => {
+                        ui_state.set(UiState::Idle);
+                        let reason = match limit {
+                            SolveLimit::Time(duration) => {
+                                format!("Time limit of {}s reached", duration.as_secs())
+                            }
+                            SolveLimit::UnimprovedModels(models) => {
+                                format!("No improvement in {models} models")
+                            }
+                            SolveLimit::UnimprovedTime(duration) => {
+                                format!("No improvement in {}s", duration.as_secs())
+                            }
+                        };
+                        let text = if best.is_some() {
+                            "timed out, not proven optimal"
+                        } else {
+                            "timed out without a model"
+                        };
+                        show_best(best);
+                        outcome.set(Some((format!("{reason} - {text}"), "text-warning")));
+                    }
+                    OptimizeStatus::Error(e) => {
+                        ui_state.set(UiState::Idle);
+                        println!("Optimization Error: {}", e);
+                    }
+                }
+            }
+            ui_state.set(UiState::Idle);
+        });
+
+        let session = start_optimization_session(template, items, state.config.clone(), tx);
+        for item_id in excluded_items {
+            if let Err(e) = session.send(SessionCommand::ExcludeItem { item_id }) {
+                println!("Warning: {e}");
+            }
+        }
+
+        if let Ok(mut guard) = state.session.lock() {
+            *guard = Some(session);
+        }
+    });
+
+    use_drop(move || {
+        if let Ok(mut guard) = app_state.peek().session.lock() {
+            *guard = None;
+        }
+    });
 
     let inner_slots = 8;
     let inner_radius = 120.0;
@@ -116,70 +293,9 @@ pub fn Inventory() -> Element {
         .collect();
 
     let on_toggle_optimization = move |_| match ui_state() {
-        UiState::Idle => {
-            let state = app_state.read();
-
-            let template = match state.template.lock() {
-                Ok(guard) => match guard.clone() {
-                    Some(tmp) => tmp,
-                    None => {
-                        println!("Optimization aborted: No active template.");
-                        return;
-                    }
-                },
-                Err(_) => {
-                    println!("Warning: Template mutex was poisoned.");
-                    return;
-                }
-            };
-
-            let items = match state.items.lock() {
-                Ok(guard) => guard.clone(),
-                Err(_) => {
-                    println!("Warning: Items mutex was poisoned.");
-                    return;
-                }
-            };
-
-            let (tx, mut rx) = unbounded_channel();
-            let flag = Arc::new(AtomicBool::new(false));
-
-            stop_flag.set(Some(flag.clone()));
-
-            spawn(async move {
-                while let Some(msg) = rx.recv().await {
-                    match msg {
-                        OptimizeStatus::Setup | OptimizeStatus::Grounding => {
-                            ui_state.set(UiState::Preparing);
-                        }
-                        OptimizeStatus::Solving => {
-                            ui_state.set(UiState::Solving);
-                        }
-                        OptimizeStatus::NewModel(new_template) => {
-                            if let Ok(mut guard) = app_state.write().template.lock() {
-                                *guard = Some(new_template);
-                            }
-                        }
-                        OptimizeStatus::Finished => {
-                            ui_state.set(UiState::Idle);
-                            stop_flag.set(None);
-                        }
-                        OptimizeStatus::Error(e) => {
-                            ui_state.set(UiState::Idle);
-                            println!("Optimization Error: {}", e);
-                        }
-                    }
-                }
-                ui_state.set(UiState::Idle);
-            });
-
-            start_optimization_worker(template, items, tx, flag);
-        }
-        _ => {
-            if let Some(flag) = stop_flag() {
-                flag.store(true, Ordering::Relaxed);
-            }
-        }
+        UiState::Idle => app_state.read().send_session_command(SessionCommand::Solve),
+        UiState::Solving => app_state.read().send_session_command(SessionCommand::Stop),
+        UiState::Preparing => {}
     };
 
     let button_text = match ui_state() {
@@ -197,6 +313,19 @@ pub fn Inventory() -> Element {
         }
     };
 
+    let quality_text = model_quality().map(|(levels, optimal)| {
+        let cost = levels
+            .iter()
+            .map(|(level, cost)| format!("@{level}: {cost}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        if optimal {
+            format!("Cost [{cost}] - proven optimal")
+        } else {
+            format!("Cost [{cost}] - best found so far")
+        }
+    });
+
     rsx! {
         div { class: "flex flex-col",
             div { class: "relative w-[700px] h-[700px] mx-auto flex flex-col",
@@ -211,6 +340,16 @@ pub fn Inventory() -> Element {
 
                         span { class: "font-bold text-sm tracking-wider", "{button_text}" }
                     }
+                    if let Some(quality_text) = quality_text {
+                        span { class: "absolute top-[calc(50%+56px)] left-1/2 transform -translate-x-1/2 text-xs text-foreground-secondary whitespace-nowrap",
+                            "{quality_text}"
+                        }
+                    }
+                    if let Some((outcome_text, outcome_color)) = outcome() {
+                        span { class: "absolute top-[calc(50%+72px)] left-1/2 transform -translate-x-1/2 text-xs {outcome_color} whitespace-nowrap",
+                            "{outcome_text}"
+                        }
+                    }
                     Circle {
                         total_slots: inner_slots,
                         radius: inner_radius,
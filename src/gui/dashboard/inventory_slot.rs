@@ -4,6 +4,7 @@ use crate::app_state::AppState;
 use crate::core::domain::item_slot::ItemSlot;
 use crate::gui::components::modal::ModalContext;
 use crate::gui::dashboard::item_selection_modal::ItemSelectionModal;
+use crate::optimization::session::SessionCommand;
 use dioxus::prelude::*;
 use dioxus_free_icons::{Icon, icons::ld_icons::LdX};
 
@@ -55,8 +56,7 @@ pub fn InventorySlot(props: InventorySlotProps) -> Element {
 
     let container_classes = format!(
         "rounded-b-full flex items-center justify-center border border-border transition-all duration-200 relative {} {}",
-        state_classes,
-        props.class
+        state_classes, props.class
     );
 
     let remove_item = move |event: Event<MouseData>| {
@@ -69,6 +69,10 @@ pub fn InventorySlot(props: InventorySlotProps) -> Element {
         {
             template.remove_item(&props.slot_type);
         }
+
+        state.send_session_command(SessionCommand::UnpinItem {
+            slot: props.slot_type,
+        });
     };
 
     rsx! {
@@ -9,6 +9,7 @@ use crate::core::domain::{item::Item, item_slot::ItemSlot};
 use crate::gui::{
     components::modal::ModalContext, dashboard::item_selection_details::ItemSelectionDetails,
 };
+use crate::optimization::session::SessionCommand;
 
 /// Properties for the `ItemSelectionModal` component.
 #[derive(Props, Clone, PartialEq)]
@@ -62,12 +63,48 @@ pub fn ItemSelectionModal(props: ItemSelectionModalProps) -> Element {
             if let Ok(mut template_guard) = state.template.lock()
                 && let Some(template) = template_guard.as_mut()
             {
-                template.set_item(props.slot_type, item);
+                template.set_item(props.slot_type, item.clone());
             }
+
+            state.send_session_command(SessionCommand::PinItem {
+                slot: props.slot_type,
+                item_id: item.id,
+            });
         }
         modal_context.write().content = None;
     };
 
+    let is_excluded = move |item_id: i32| {
+        app_state
+            .read()
+            .excluded_items
+            .lock()
+            .map(|excluded| excluded.contains(&item_id))
+            .unwrap_or(false)
+    };
+
+    let handle_toggle_exclusion = move |_| {
+        if let Some(item) = selected_item.read().clone() {
+            let state = app_state.write().clone();
+            let command = match state.excluded_items.lock() {
+                Ok(mut excluded) => {
+                    if excluded.remove(&item.id) {
+                        SessionCommand::IncludeItem { item_id: item.id }
+                    } else {
+                        excluded.insert(item.id);
+                        SessionCommand::ExcludeItem { item_id: item.id }
+                    }
+                }
+                Err(_) => {
+                    println!("Warning: Excluded items mutex was poisoned.");
+                    return;
+                }
+            };
+
+            state.send_session_command(command);
+        }
+    };
+
     let item_rows = items.iter().map(|item_ref| {
         let item = (*item_ref).clone();
         let selected = selected_item.read().clone();
@@ -77,13 +114,18 @@ pub fn ItemSelectionModal(props: ItemSelectionModalProps) -> Element {
         } else {
             "hover:bg-accent/20"
         };
+        let excluded = if is_excluded(item.id) {
+            "line-through text-foreground-secondary"
+        } else {
+            ""
+        };
 
         rsx! {
             tr {
                 key: "{item.id}",
                 class: "cursor-pointer {color}",
                 onclick: move |_| selected_item.set(Some(item.clone())),
-                td { class: "text-left", "{item.name}" }
+                td { class: "text-left {excluded}", "{item.name}" }
                 td { class: "text-right font-mono w-36", {format!("{:.2}", item_ref.utility)} }
             }
         }
@@ -105,6 +147,11 @@ pub fn ItemSelectionModal(props: ItemSelectionModalProps) -> Element {
         }
     };
 
+    let exclusion_text = match selected_item.read().as_ref() {
+        Some(item) if is_excluded(item.id) => "Include",
+        _ => "Exclude",
+    };
+
     rsx! {
         div { class: "flex flex-col gap-4",
             div { class: "flex gap-4 h-[30vh] w-[80vw] max-w-[800px]",
@@ -131,7 +178,14 @@ pub fn ItemSelectionModal(props: ItemSelectionModalProps) -> Element {
                     "Close"
                 }
                 button {
-                    class: "ml-auto cursor-pointer py-1 px-6 rounded-lg bg-accent hover:bg-accent/80",
+                    class: "ml-auto cursor-pointer py-1 px-6 text-foreground-secondary hover:text-foreground/80",
+                    disabled: selected_item.read().is_none(),
+                    onclick: handle_toggle_exclusion,
+                    title: "Exclude or include the item in the optimization",
+                    "{exclusion_text}"
+                }
+                button {
+                    class: "cursor-pointer py-1 px-6 rounded-lg bg-accent hover:bg-accent/80",
                     onclick: handle_select,
                     "Select"
                 }
@@ -0,0 +1,42 @@
+//! This module defines the message panel component for the dashboard.
+
+use dioxus::prelude::*;
+
+use crate::app_state::AppState;
+
+/// The MessagePanel component lists the messages clingo reported for the current session.
+///
+/// Messages such as undefined atoms usually point to a mismatch between the encoding
+/// and the generated instance, so they are shown instead of being dropped.
+#[component]
+pub fn MessagePanel() -> Element {
+    let app_state = use_context::<Signal<AppState>>();
+
+    let messages = use_memo(move || {
+        let state = app_state.read();
+        state
+            .clingo_messages
+            .lock()
+            .map(|guard| guard.clone())
+            .unwrap_or_default()
+    });
+
+    if messages().is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div { class: "w-full max-w-[1600px] mx-auto px-4",
+            div { class: "bg-card border border-warning rounded-lg p-4 shadow-sm w-full",
+                h3 { class: "text-warning font-bold border-b border-border pb-2 mb-3",
+                    "Solver Messages"
+                }
+                ul { class: "list-disc list-inside text-xs font-mono text-foreground-secondary",
+                    {messages().into_iter().map(|message| rsx! {
+                        li { "{message}" }
+                    })}
+                }
+            }
+        }
+    }
+}
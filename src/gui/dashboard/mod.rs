@@ -8,7 +8,16 @@ pub mod attributes;
 pub mod dashboard_page;
 pub use dashboard_page::DashboardPage;
 pub mod circle;
+pub mod conflict_panel;
+pub mod editor_panel;
+pub mod instance_panel;
 pub mod inventory;
 pub mod inventory_slot;
 pub mod item_selection_details;
 pub mod item_selection_modal;
+pub mod message_panel;
+pub mod priority_editor;
+pub mod requirement_editor;
+pub mod statistics_panel;
+pub mod template_draft;
+pub mod weight_editor;
@@ -0,0 +1,68 @@
+//! This module defines the priority editor component for the dashboard.
+
+use dioxus::prelude::*;
+use strum::IntoEnumIterator;
+
+use crate::app_state::AppState;
+use crate::core::domain::priority_profile::{PriorityGroup, PriorityProfile};
+use crate::gui::dashboard::{editor_panel::EditorPanel, template_draft::use_template_draft};
+use crate::optimization::session::SessionCommand;
+
+/// The PriorityEditor component lets the user edit the priority levels of the template.
+///
+/// Stat groups on a higher level are optimized first, e.g. giving the resists the highest
+/// level caps all resists before any other stat is considered. Changes are collected in a
+/// draft and applied at once, because applying them grounds the problem instance again.
+#[component]
+pub fn PriorityEditor() -> Element {
+    let mut app_state = use_context::<Signal<AppState>>();
+
+    let mut draft = use_template_draft(|template| template.priorities.clone());
+
+    let on_apply = move |_: MouseEvent| {
+        let priorities = draft();
+        if let Ok(mut guard) = app_state.write().template.lock()
+            && let Some(template) = guard.as_mut()
+        {
+            template.priorities = priorities.clone();
+        }
+        app_state
+            .read()
+            .send_session_command(SessionCommand::SetPriorities(priorities));
+    };
+
+    let on_reset = move |_: MouseEvent| draft.set(PriorityProfile::default());
+
+    rsx! {
+        EditorPanel {
+            title: "Priorities",
+            reset_label: "Reset",
+            on_reset,
+            on_apply,
+            {
+                PriorityGroup::iter()
+                    .map(|group| {
+                        let level = draft.read().level(group);
+                        rsx! {
+                            label { class: "flex flex-col text-xs",
+                                span { class: "font-medium text-foreground/90", "{group}" }
+                                input {
+                                    class: "bg-transparent border border-border rounded px-2 py-1 font-mono text-foreground-secondary",
+                                    r#type: "number",
+                                    step: "1",
+                                    min: "0",
+                                    max: "9",
+                                    value: "{level}",
+                                    oninput: move |event| {
+                                        if let Ok(level) = event.value().parse::<u16>() {
+                                            draft.write().set_level(group, level);
+                                        }
+                                    },
+                                }
+                            }
+                        }
+                    })
+            }
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! This module defines the requirement editor component for the dashboard.
+
+use dioxus::prelude::*;
+
+use crate::app_state::AppState;
+use crate::core::domain::stat_requirement::StatRequirement;
+use crate::gui::dashboard::{editor_panel::EditorPanel, template_draft::use_template_draft};
+use crate::optimization::session::SessionCommand;
+
+/// The RequirementEditor component lets the user edit the stat requirements of the template.
+///
+/// Each relevant stat can get a minimum total and can be required to reach its cap.
+/// Minimums apply to the capped total and are clamped to the highest total the stat
+/// can reach, see [`StatRequirement::at_least`]. Templates violating a requirement are
+/// rejected by the optimization. Like the weights, the requirements are collected in a
+/// draft and applied at once.
+#[component]
+pub fn RequirementEditor() -> Element {
+    let mut app_state = use_context::<Signal<AppState>>();
+
+    let mut draft = use_template_draft(|template| template.requirements.clone());
+
+    let stats = use_memo(move || {
+        let state = app_state.read();
+        state
+            .template
+            .lock()
+            .ok()
+            .and_then(|guard| {
+                guard
+                    .as_ref()
+                    .map(|template| template.class.relevant_stats())
+            })
+            .unwrap_or_default()
+    });
+
+    if stats().is_empty() {
+        return rsx! {};
+    }
+
+    let on_apply = move |_: MouseEvent| {
+        let requirements = draft();
+        if let Ok(mut guard) = app_state.write().template.lock()
+            && let Some(template) = guard.as_mut()
+        {
+            template.requirements = requirements.clone();
+        }
+        app_state
+            .read()
+            .send_session_command(SessionCommand::SetRequirements(requirements));
+    };
+
+    let on_clear = move |_: MouseEvent| draft.set(Vec::new());
+
+    rsx! {
+        EditorPanel {
+            title: "Stat Requirements",
+            reset_label: "Clear",
+            on_reset: on_clear,
+            on_apply,
+            {
+                stats()
+                    .into_iter()
+                    .map(|stat| {
+                        let minimum = draft
+                            .read()
+                            .iter()
+                            .find_map(|requirement| match requirement {
+                                StatRequirement::AtLeast(required, value) if *required == stat => {
+                                    Some(value.to_string())
+                                }
+                                _ => None,
+                            })
+                            .unwrap_or_default();
+                        let highest = StatRequirement::highest_total(stat);
+                        let capped = draft
+                            .read()
+                            .contains(&StatRequirement::Capped(stat));
+                        let label_color = if draft
+                            .read()
+                            .iter()
+                            .any(|requirement| requirement.stat() == stat)
+                        {
+                            "text-accent"
+                        } else {
+                            "text-foreground/90"
+                        };
+                        rsx! {
+                            div { class: "flex flex-col text-xs",
+                                span { class: "font-medium {label_color}", "{stat}" }
+                                div { class: "flex items-center gap-2",
+                                    input {
+                                        class: "w-full bg-transparent border border-border rounded px-2 py-1 font-mono text-foreground-secondary",
+                                        r#type: "number",
+                                        min: "0",
+                                        max: "{highest}",
+                                        placeholder: "min",
+                                        value: "{minimum}",
+                                        oninput: move |event| {
+                                            let mut requirements = draft.write();
+                                            requirements
+                                                .retain(|requirement| {
+                                                    !matches!(requirement, StatRequirement::AtLeast(..))
+                                                        || requirement.stat() != stat
+                                                });
+                                            if let Ok(value) = event.value().parse::<u32>()
+                                                && value > 0
+                                            {
+                                                let value = u16::try_from(value).unwrap_or(u16::MAX);
+                                                requirements.push(StatRequirement::at_least(stat, value));
+                                            }
+                                        },
+                                    }
+                                    label { class: "flex items-center gap-1 text-foreground-secondary whitespace-nowrap",
+                                        input {
+                                            r#type: "checkbox",
+                                            checked: capped,
+                                            onchange: move |event| {
+                                                let mut requirements = draft.write();
+                                                requirements.retain(|requirement| *requirement != StatRequirement::Capped(stat));
+                                                if event.checked() {
+                                                    requirements.push(StatRequirement::Capped(stat));
+                                                }
+                                            },
+                                        }
+                                        "cap"
+                                    }
+                                }
+                            }
+                        }
+                    })
+            }
+        }
+    }
+}
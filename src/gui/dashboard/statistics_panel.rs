@@ -0,0 +1,62 @@
+//! This module defines the statistics panel component for the dashboard.
+
+use dioxus::prelude::*;
+
+use crate::app_state::AppState;
+
+/// The StatisticsPanel component displays the solver statistics of the last optimization run.
+///
+/// Grounding and solving times are shown separately, so it is visible whether
+/// a slow optimization is caused by the size of the ground program or by the search.
+#[component]
+pub fn StatisticsPanel() -> Element {
+    let app_state = use_context::<Signal<AppState>>();
+
+    let statistics = use_memo(move || {
+        let state = app_state.read();
+        state.statistics.lock().ok().and_then(|guard| guard.clone())
+    });
+
+    let Some(statistics) = statistics() else {
+        return rsx! {};
+    };
+
+    let rows = [
+        (
+            "Ground time",
+            format!("{:.3}s", statistics.ground_time.as_secs_f64()),
+        ),
+        (
+            "Solve time",
+            format!("{:.3}s", statistics.solve_time.as_secs_f64()),
+        ),
+        ("Models", statistics.models.to_string()),
+        ("Choices", statistics.choices.to_string()),
+        ("Conflicts", statistics.conflicts.to_string()),
+        ("Rules", statistics.rules.to_string()),
+        ("Atoms", statistics.atoms.to_string()),
+    ];
+
+    rsx! {
+        div { class: "w-full max-w-[1600px] mx-auto pb-10 px-4",
+            div { class: "bg-card border border-border rounded-lg p-4 shadow-sm w-full",
+                h3 { class: "text-accent font-bold border-b border-border pb-2 mb-3",
+                    "Solver Statistics"
+                }
+                div { class: "grid grid-cols-2 md:grid-cols-4 xl:grid-cols-7 gap-x-6 gap-y-1",
+                    {
+                        rows.into_iter()
+                            .map(|(label, value)| {
+                                rsx! {
+                                    div { class: "flex flex-col text-xs",
+                                        span { class: "text-foreground/90 font-medium", "{label}" }
+                                        span { class: "text-foreground-secondary font-mono", "{value}" }
+                                    }
+                                }
+                            })
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,40 @@
+//! This module provides the draft hook shared by the template editors of the dashboard.
+
+use dioxus::prelude::*;
+
+use crate::app_state::AppState;
+use crate::core::domain::template::Template;
+
+/// Creates a draft of a part of the current template.
+///
+/// The draft follows the template: whenever the selected part changes, e.g. because
+/// another template was opened or the template was replaced, the draft is reset to it.
+/// Edits that were not applied yet are kept as long as the selected part stays the same,
+/// so streamed models do not discard them.
+///
+/// # Parameters
+/// - `select`: Selects the edited part of the template.
+///
+/// # Returns
+/// - The draft signal, holding the default value while no template is open.
+pub fn use_template_draft<T>(select: fn(&Template) -> T) -> Signal<T>
+where
+    T: Clone + Default + PartialEq + 'static,
+{
+    let app_state = use_context::<Signal<AppState>>();
+
+    let current = use_memo(move || {
+        let state = app_state.read();
+        state
+            .template
+            .lock()
+            .ok()
+            .and_then(|guard| guard.as_ref().map(select))
+            .unwrap_or_default()
+    });
+
+    let mut draft = use_signal(move || current.peek().clone());
+    use_effect(move || draft.set(current()));
+
+    draft
+}
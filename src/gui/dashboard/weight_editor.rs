@@ -0,0 +1,91 @@
+//! This module defines the weight editor component for the dashboard.
+
+use dioxus::prelude::*;
+
+use crate::app_state::AppState;
+use crate::core::domain::weight_profile::WeightProfile;
+use crate::gui::dashboard::{editor_panel::EditorPanel, template_draft::use_template_draft};
+use crate::optimization::session::SessionCommand;
+
+/// The WeightEditor component lets the user edit the weight profile of the template.
+///
+/// Only the stats relevant for the class are listed, invalid weights are ignored.
+/// Changes are collected in a draft and applied to the template and the optimization
+/// session at once, because applying them grounds the problem instance again.
+#[component]
+pub fn WeightEditor() -> Element {
+    let mut app_state = use_context::<Signal<AppState>>();
+
+    let mut draft = use_template_draft(|template| template.weights.clone());
+
+    let stats = use_memo(move || {
+        let state = app_state.read();
+        state
+            .template
+            .lock()
+            .ok()
+            .and_then(|guard| {
+                guard
+                    .as_ref()
+                    .map(|template| template.class.relevant_stats())
+            })
+            .unwrap_or_default()
+    });
+
+    if stats().is_empty() {
+        return rsx! {};
+    }
+
+    let on_apply = move |_: MouseEvent| {
+        let weights = draft();
+        if let Ok(mut guard) = app_state.write().template.lock()
+            && let Some(template) = guard.as_mut()
+        {
+            template.weights = weights.clone();
+        }
+        app_state
+            .read()
+            .send_session_command(SessionCommand::SetWeights(weights));
+    };
+
+    let on_reset = move |_: MouseEvent| draft.set(WeightProfile::default());
+
+    rsx! {
+        EditorPanel {
+            title: "Stat Weights",
+            reset_label: "Reset",
+            on_reset,
+            on_apply,
+            {
+                stats()
+                    .into_iter()
+                    .map(|stat| {
+                        let weight = draft.read().weight(stat);
+                        let label_color = if draft.read().is_custom(stat) {
+                            "text-accent"
+                        } else {
+                            "text-foreground/90"
+                        };
+                        rsx! {
+                            label { class: "flex flex-col text-xs",
+                                span { class: "font-medium {label_color}", "{stat}" }
+                                input {
+                                    class: "bg-transparent border border-border rounded px-2 py-1 font-mono text-foreground-secondary",
+                                    r#type: "number",
+                                    step: "0.01",
+                                    min: "0",
+                                    max: WeightProfile::MAX_WEIGHT.to_string(),
+                                    value: "{weight}",
+                                    oninput: move |event| {
+                                        if let Ok(weight) = event.value().parse::<f32>() {
+                                            draft.write().set_weight(stat, weight);
+                                        }
+                                    },
+                                }
+                            }
+                        }
+                    })
+            }
+        }
+    }
+}
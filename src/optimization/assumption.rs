@@ -0,0 +1,204 @@
+//! This module provides assumptions and item toggles for what-if queries on the optimization.
+//!
+//! Assumptions restrict a single solve call without changing the grounded program,
+//! e.g. to answer "what is the best template if I keep this chest?".
+//! Item availability is kept in external atoms and stays in effect until it is changed again.
+
+use crate::clingo::{
+    control::Control,
+    literal::{Literal, TruthValue},
+    symbol::Symbol,
+    symbolic_atoms::SymbolicAtoms,
+};
+use crate::core::domain::{item::Item, item_slot::ItemSlot, stat_requirement::StatRequirement};
+use crate::optimization::instance::requirement_external;
+use anyhow::{Result, anyhow};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// An assumption the optimization has to respect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Assumption {
+    /// The item is placed in the slot, as if the user had put it there.
+    PinItem {
+        /// The slot the item is forced into.
+        slot: ItemSlot,
+        /// The ID of the forced item.
+        item_id: i32,
+    },
+    /// The stat requirement of the template is enforced.
+    Require(StatRequirement),
+}
+
+impl Assumption {
+    /// Converts the assumption into the solver literals it stands for.
+    ///
+    /// # Parameters
+    /// - `atoms`: The symbolic atoms of the grounded program.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<Literal>)` containing the literals to assume.
+    ///
+    /// # Errors
+    /// - `Err(anyhow::Error)` if a pinned item cannot be placed in its slot at all,
+    ///   if a requirement is not part of the grounded program,
+    ///   or if the lookup of the atoms fails.
+    pub fn literals(&self, atoms: &SymbolicAtoms) -> Result<Vec<Literal>> {
+        match self {
+            Assumption::PinItem { slot, item_id } => {
+                let symbol = Symbol::create_function(
+                    "slot_taken",
+                    &[
+                        Symbol::create_number(slot.id()),
+                        Symbol::create_number(*item_id),
+                    ],
+                    true,
+                )?;
+
+                let literal = atoms.literal(&symbol)?.ok_or_else(|| {
+                    anyhow!("Item {item_id} cannot be placed in slot {}", slot.name())
+                })?;
+
+                Ok(vec![literal])
+            }
+            Assumption::Require(requirement) => {
+                let symbol = requirement_external(requirement).to_symbol()?;
+
+                let literal = atoms.literal(&symbol)?.ok_or_else(|| {
+                    anyhow!("Requirement {requirement} is not part of the instance")
+                })?;
+
+                Ok(vec![literal])
+            }
+        }
+    }
+
+    /// Describes the assumption for the user, e.g. `1234 (Ring of Power) pinned to ring`.
+    ///
+    /// # Parameters
+    /// - `items`: The available items, used to look up item names.
+    ///
+    /// # Returns
+    /// - A short description of the assumption.
+    pub fn describe(&self, items: &[Arc<Item>]) -> String {
+        let item_name = |item_id: i32| {
+            items.iter().find(|item| item.id == item_id).map_or_else(
+                || item_id.to_string(),
+                |item| format!("{item_id} ({})", item.name),
+            )
+        };
+
+        match self {
+            Assumption::PinItem { slot, item_id } => {
+                format!("{} pinned to {slot}", item_name(*item_id))
+            }
+            Assumption::Require(requirement) => format!("requirement {requirement}"),
+        }
+    }
+}
+
+/// Finds the assumptions that take part in an unsatisfiable core.
+///
+/// # Parameters
+/// - `assumptions`: The assumptions of the unsatisfiable solve call.
+/// - `atoms`: The symbolic atoms of the grounded program.
+/// - `core`: The core literals reported by the solver.
+///
+/// # Returns
+/// - `Ok(Vec<Assumption>)` containing the assumptions with a literal in the core.
+///
+/// # Errors
+/// - `Err(anyhow::Error)` if an assumption cannot be converted into literals.
+pub fn core_assumptions(
+    assumptions: &[Assumption],
+    atoms: &SymbolicAtoms,
+    core: &[Literal],
+) -> Result<Vec<Assumption>> {
+    let core = core.iter().copied().collect::<HashSet<_>>();
+
+    let mut conflicting = Vec::new();
+    for assumption in assumptions {
+        if assumption
+            .literals(atoms)?
+            .iter()
+            .any(|literal| core.contains(literal))
+        {
+            conflicting.push(*assumption);
+        }
+    }
+    Ok(conflicting)
+}
+
+/// Explains an unsatisfiable core in a single sentence.
+///
+/// # Parameters
+/// - `core`: The assumptions of the core, see [`core_assumptions`].
+/// - `items`: The available items, used to look up item names.
+///
+/// # Returns
+/// - A sentence such as `1234 (Ring of Power) pinned to ring and 42 (Belt) pinned to belt cannot both hold`.
+pub fn explain_core(core: &[Assumption], items: &[Arc<Item>]) -> String {
+    let descriptions = core
+        .iter()
+        .map(|assumption| assumption.describe(items))
+        .collect::<Vec<_>>();
+
+    match descriptions.as_slice() {
+        [] => "No template exists even without any pinned items or requirements".to_owned(),
+        [single] => format!("{single} cannot hold"),
+        [first, second] => format!("{first} and {second} cannot both hold"),
+        [rest @ .., last] => format!("{} and {last} cannot all hold", rest.join(", ")),
+    }
+}
+
+/// Converts a list of assumptions into the solver literals they stand for.
+///
+/// # Parameters
+/// - `assumptions`: The assumptions to convert.
+/// - `atoms`: The symbolic atoms of the grounded program.
+///
+/// # Returns
+/// - `Ok(Vec<Literal>)` containing the literals to assume.
+///
+/// # Errors
+/// - `Err(anyhow::Error)` if any of the assumptions cannot be converted.
+pub fn assumption_literals(
+    assumptions: &[Assumption],
+    atoms: &SymbolicAtoms,
+) -> Result<Vec<Literal>> {
+    let mut literals = Vec::new();
+    for assumption in assumptions {
+        literals.extend(assumption.literals(atoms)?);
+    }
+    Ok(literals)
+}
+
+/// Enables or disables an item for all following solve calls.
+///
+/// # Parameters
+/// - `control`: The control holding the grounded program.
+/// - `item_id`: The ID of the item.
+/// - `available`: Whether the item may be chosen.
+///
+/// # Returns
+/// - `Ok(true)` if the availability of the item was changed.
+/// - `Ok(false)` if the item is not part of the grounded program.
+///
+/// # Errors
+/// - `Err(anyhow::Error)` if the lookup or the assignment fails.
+pub fn set_item_availability(control: &Control, item_id: i32, available: bool) -> Result<bool> {
+    let symbol = Symbol::create_function("available", &[Symbol::create_number(item_id)], true)?;
+
+    let Some(literal) = control.symbolic_atoms()?.literal(&symbol)? else {
+        return Ok(false);
+    };
+
+    let value = if available {
+        TruthValue::True
+    } else {
+        TruthValue::False
+    };
+    control.assign_external(literal, value)?;
+
+    Ok(true)
+}
@@ -0,0 +1,32 @@
+//! This module provides the ASP encoding of the optimization problem.
+//!
+//! The encoding is compiled into the binary, so a release build does not depend
+//! on the source tree. For iterating on the encoding, an external file can be
+//! used instead by setting `encoding_path` in the optimization configuration.
+
+use anyhow::{Context, Result};
+use std::borrow::Cow;
+
+/// The encoding compiled into the binary.
+pub const ENCODING: &str = include_str!("encoding.lp");
+
+/// Loads the encoding to use for the optimization.
+///
+/// # Parameters
+/// - `override_path`: An optional path to an external encoding file that replaces the built-in encoding.
+///
+/// # Returns
+/// - `Ok(Cow<str>)` containing the external encoding if a path was given, the built-in encoding otherwise.
+///
+/// # Errors
+/// - `Err(anyhow::Error)` if the external encoding file could not be read.
+pub fn load_encoding(override_path: Option<&str>) -> Result<Cow<'static, str>> {
+    match override_path {
+        Some(path) => {
+            let encoding = std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read encoding from '{path}'"))?;
+            Ok(Cow::Owned(encoding))
+        }
+        None => Ok(Cow::Borrowed(ENCODING)),
+    }
+}
@@ -0,0 +1,235 @@
+//! This module provides a typed builder for the ASP facts of the problem instance.
+//!
+//! Building facts from typed terms instead of formatted strings guarantees that
+//! every fact is syntactically valid. String terms are escaped, and constants are
+//! checked to be valid identifiers.
+
+use crate::clingo::{error::ClingoError, symbol::Symbol};
+use anyhow::{Result, bail};
+use std::fmt::{self, Display};
+
+/// A term used as argument of a fact.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Term {
+    /// An integer, e.g. `42`.
+    Number(i32),
+    /// A symbolic constant, e.g. `ring`.
+    Constant(String),
+    /// A string, e.g. `"Ring of \"Power\""`.
+    String(String),
+}
+
+impl Term {
+    /// Creates a symbolic constant.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the constant.
+    ///
+    /// # Returns
+    /// - A `Term::Constant` with the given name.
+    pub fn constant(name: impl Into<String>) -> Self {
+        Term::Constant(name.into())
+    }
+
+    /// Converts the term into a clingo symbol.
+    ///
+    /// # Returns
+    /// - `Ok(Symbol)` containing the symbol of the term.
+    ///
+    /// # Errors
+    /// - `Err(ClingoError)` if the symbol could not be created.
+    pub fn to_symbol(&self) -> Result<Symbol, ClingoError> {
+        match self {
+            Term::Number(number) => Ok(Symbol::create_number(*number)),
+            Term::Constant(name) => Symbol::create_id(name, true),
+            Term::String(string) => Symbol::create_string(string),
+        }
+    }
+}
+
+impl From<i32> for Term {
+    fn from(number: i32) -> Self {
+        Term::Number(number)
+    }
+}
+
+impl From<u16> for Term {
+    fn from(number: u16) -> Self {
+        Term::Number(i32::from(number))
+    }
+}
+
+impl From<&str> for Term {
+    fn from(string: &str) -> Self {
+        Term::String(string.to_owned())
+    }
+}
+
+impl From<String> for Term {
+    fn from(string: String) -> Self {
+        Term::String(string)
+    }
+}
+
+impl Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Term::Number(number) => write!(f, "{number}"),
+            Term::Constant(name) => write!(f, "{name}"),
+            Term::String(string) => {
+                write!(f, "\"")?;
+                for c in string.chars() {
+                    match c {
+                        '"' => write!(f, "\\\"")?,
+                        '\\' => write!(f, "\\\\")?,
+                        '\n' => write!(f, "\\n")?,
+                        c => write!(f, "{c}")?,
+                    }
+                }
+                write!(f, "\"")
+            }
+        }
+    }
+}
+
+/// A fact of the problem instance, e.g. `item(1234, ring, "Ring of Power").`
+///
+/// # Examples
+/// ```
+/// use templess::optimization::fact::{Fact, Term};
+/// let fact = Fact::new("item")
+///     .arg(1234)
+///     .arg(Term::constant("ring"))
+///     .arg("Ring of \"Power\"");
+/// assert_eq!(fact.render().unwrap(), r#"item(1234, ring, "Ring of \"Power\"")."#);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fact {
+    name: String,
+    arguments: Vec<Term>,
+}
+
+impl Fact {
+    /// Creates a fact without arguments.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the predicate.
+    ///
+    /// # Returns
+    /// - A new `Fact` instance.
+    pub fn new(name: impl Into<String>) -> Self {
+        Fact {
+            name: name.into(),
+            arguments: Vec::new(),
+        }
+    }
+
+    /// Appends an argument to the fact.
+    ///
+    /// # Parameters
+    /// - `term`: The argument, numbers and strings convert into terms directly.
+    ///
+    /// # Returns
+    /// - The fact with the argument appended.
+    pub fn arg(mut self, term: impl Into<Term>) -> Self {
+        self.arguments.push(term.into());
+        self
+    }
+
+    /// Renders the atom of the fact without the terminating period,
+    /// e.g. to declare it as external.
+    ///
+    /// # Returns
+    /// - `Ok(String)` containing the atom, e.g. `available(1234)`.
+    ///
+    /// # Errors
+    /// - `Err(anyhow::Error)` if the predicate name or a constant is not a valid identifier.
+    pub fn atom(&self) -> Result<String> {
+        self.validate()?;
+
+        let mut atom = String::new();
+        self.write_atom(&mut atom)?;
+        Ok(atom)
+    }
+
+    /// Renders the fact including the terminating period, e.g. `available(1234).`
+    ///
+    /// # Returns
+    /// - `Ok(String)` containing the fact.
+    ///
+    /// # Errors
+    /// - `Err(anyhow::Error)` if the predicate name or a constant is not a valid identifier.
+    pub fn render(&self) -> Result<String> {
+        Ok(format!("{}.", self.atom()?))
+    }
+
+    /// Converts the fact into a clingo symbol.
+    ///
+    /// # Returns
+    /// - `Ok(Symbol)` containing the function symbol of the fact.
+    ///
+    /// # Errors
+    /// - `Err(anyhow::Error)` if the fact is not valid or the symbol could not be created.
+    pub fn to_symbol(&self) -> Result<Symbol> {
+        self.validate()?;
+
+        let arguments = self
+            .arguments
+            .iter()
+            .map(Term::to_symbol)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Symbol::create_function(&self.name, &arguments, true)?)
+    }
+
+    /// Checks that the predicate name and all constants are valid identifiers.
+    fn validate(&self) -> Result<()> {
+        if !is_identifier(&self.name) {
+            bail!("'{}' is not a valid predicate name", self.name);
+        }
+
+        for term in &self.arguments {
+            if let Term::Constant(name) = term
+                && !is_identifier(name)
+            {
+                bail!("'{name}' is not a valid constant in fact '{}'", self.name);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes the atom of the fact without validating it.
+    fn write_atom(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if self.arguments.is_empty() {
+            return Ok(());
+        }
+
+        write!(f, "(")?;
+        for (index, term) in self.arguments.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{term}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Formats the fact without validating it, use [`Fact::render`] to reject invalid names.
+impl Display for Fact {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_atom(f)?;
+        write!(f, ".")
+    }
+}
+
+/// Checks whether a name is a valid ASP identifier,
+/// i.e. it starts with a lowercase letter, optionally after underscores.
+fn is_identifier(name: &str) -> bool {
+    let trimmed = name.trim_start_matches('_');
+    trimmed.starts_with(|c: char| c.is_ascii_lowercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '\'')
+}
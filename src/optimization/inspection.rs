@@ -0,0 +1,108 @@
+//! This module provides a summary of the grounded problem instance for debugging.
+//!
+//! The summary counts the grounded item atoms and compares them with the items
+//! the instance was generated from, which reveals items lost during instance generation.
+
+use crate::clingo::control::Control;
+use crate::clingo::signature::Signature;
+use crate::core::domain::item::Item;
+use anyhow::{Context, Result};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// A summary of the grounded problem instance.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InstanceSummary {
+    /// The number of items the instance was generated from.
+    pub expected_items: usize,
+    /// The number of grounded `item/3` atoms.
+    pub item_atoms: usize,
+    /// The number of grounded `item_bonus/3` atoms.
+    pub item_bonus_atoms: usize,
+    /// The number of `available/1` atoms declared as external.
+    pub available_externals: usize,
+    /// The number of grounded theory atoms.
+    pub theory_atoms: usize,
+    /// The IDs of items without a grounded `item/3` atom.
+    pub missing_items: Vec<i32>,
+    /// The IDs of grounded `item/3` atoms without a matching item.
+    pub unexpected_items: Vec<i32>,
+}
+
+impl InstanceSummary {
+    /// Checks whether the grounded items match the items the instance was generated from.
+    ///
+    /// # Returns
+    /// - `true` if every item has exactly one grounded `item/3` atom.
+    pub fn matches_items(&self) -> bool {
+        self.missing_items.is_empty()
+            && self.unexpected_items.is_empty()
+            && self.item_atoms == self.expected_items
+    }
+}
+
+/// Summarizes the grounded problem instance of a control.
+///
+/// # Parameters
+/// - `control`: The control holding the grounded instance.
+/// - `items`: The items the instance was generated from.
+///
+/// # Returns
+/// - `Ok(InstanceSummary)` containing the atom counts and item mismatches.
+///
+/// # Errors
+/// - `Err(anyhow::Error)` if the atoms of the control could not be inspected.
+pub fn summarize_instance(control: &Control, items: &[Arc<Item>]) -> Result<InstanceSummary> {
+    let atoms = control.symbolic_atoms()?;
+
+    let mut grounded_items = HashSet::new();
+    let mut unexpected_items = Vec::new();
+    let mut item_atoms = 0;
+
+    let expected = items.iter().map(|item| item.id).collect::<HashSet<_>>();
+
+    for atom in atoms.iter_signature(&Signature::new("item", 3, true)?)? {
+        item_atoms += 1;
+
+        let symbol = atom.symbol()?;
+        let id = symbol
+            .arguments()?
+            .first()
+            .context("Expected an item ID at index 0 in item/3")?
+            .number()?;
+
+        grounded_items.insert(id);
+        if !expected.contains(&id) {
+            unexpected_items.push(id);
+        }
+    }
+
+    let item_bonus_atoms = atoms
+        .iter_signature(&Signature::new("item_bonus", 3, true)?)?
+        .count();
+
+    let mut available_externals = 0;
+    for atom in atoms.iter_signature(&Signature::new("available", 1, true)?)? {
+        if atom.is_external()? {
+            available_externals += 1;
+        }
+    }
+
+    let mut missing_items = items
+        .iter()
+        .map(|item| item.id)
+        .filter(|id| !grounded_items.contains(id))
+        .collect::<Vec<_>>();
+    missing_items.sort_unstable();
+    unexpected_items.sort_unstable();
+
+    Ok(InstanceSummary {
+        expected_items: items.len(),
+        item_atoms,
+        item_bonus_atoms,
+        available_externals,
+        theory_atoms: control.theory_atoms()?.len()?,
+        missing_items,
+        unexpected_items,
+    })
+}
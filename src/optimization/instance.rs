@@ -1,126 +1,328 @@
-//! This module provides functions for generating ASP atoms for the optimization.
+//! This module provides functions for generating the problem instance of the optimization.
+//!
+//! All facts are built with [`Fact`], so names and strings taken from the item
+//! database cannot break the instance. The facts are added to the control as
+//! program text, the external atoms are declared through the backend.
 
+use crate::clingo::backend::ExternalType;
+use crate::clingo::control::Control;
 use crate::core::domain::{
-    class::Class, item::Item, item_slot::ItemSlot, stat::Stat, template::Template,
+    class::Class, item::Item, item_slot::ItemSlot, priority_profile::PriorityProfile, stat::Stat,
+    stat_requirement::StatRequirement, template::Template, weight_profile::WeightProfile,
 };
+use crate::optimization::fact::{Fact, Term};
 use anyhow::Result;
 use std::fmt::Write;
 use std::sync::Arc;
 use strum::IntoEnumIterator;
 
-/// Generates item related ASP atoms.
+/// The facts and external atoms of a problem instance.
+#[derive(Debug, Clone, Default)]
+pub struct Instance {
+    facts: Vec<Fact>,
+    externals: Vec<Fact>,
+    requirements: Vec<Fact>,
+}
+
+impl Instance {
+    /// Builds the problem instance of a template.
+    ///
+    /// # Parameters
+    /// - `template`: The template providing the class, the weights and the stat requirements.
+    /// - `items`: The available items to consider during optimization.
+    ///
+    /// # Returns
+    /// - The problem instance.
+    pub fn new(template: &Template, items: &[Arc<Item>]) -> Self {
+        let mut facts = class_facts(template.class);
+        facts.extend(slot_facts());
+        facts.extend(stat_facts(&template.weights, &template.priorities));
+        facts.extend(item_facts(items));
+        facts.extend(weapon_facts(template.class, items));
+
+        Instance {
+            facts,
+            externals: available_externals(items),
+            requirements: template
+                .requirements
+                .iter()
+                .map(requirement_external)
+                .collect(),
+        }
+    }
+
+    /// Returns the number of facts and external atoms in the instance.
+    pub fn len(&self) -> usize {
+        self.facts.len() + self.externals.len() + self.requirements.len()
+    }
+
+    /// Checks whether the instance is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds the instance to a control.
+    ///
+    /// The facts are added as program text to the `base` part, so the grounder
+    /// can simplify them out of the rules of the encoding. The external atoms are
+    /// declared through the backend, avoiding to print and parse an `#external`
+    /// directive per item. Item externals are true by default. Requirement externals
+    /// are false by default and enabled by assumption when solving, so violated
+    /// requirements show up in the unsatisfiable core.
+    ///
+    /// # Parameters
+    /// - `control`: The control to add the instance to.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the instance was added successfully.
+    ///
+    /// # Errors
+    /// - `Err(anyhow::Error)` if a fact is invalid or the control failed to add it.
+    pub fn add_to(&self, control: &Control) -> Result<()> {
+        let mut program = String::new();
+        let mut externals = Vec::new();
+
+        for entry in self.entries() {
+            match entry {
+                Entry::Fact(fact) => writeln!(program, "{}", fact.render()?)?,
+                Entry::External(atom, value) => externals.push((atom.to_symbol()?, value)),
+            }
+        }
+
+        control.add("base", &[], &program)?;
+
+        let mut backend = control.backend()?;
+        for (symbol, value) in externals {
+            let atom = backend.add_atom(Some(&symbol))?;
+            let value = if value {
+                ExternalType::True
+            } else {
+                ExternalType::False
+            };
+            backend.external(atom, value)?;
+        }
+
+        Ok(())
+    }
+
+    /// Renders the instance as an ASP program.
+    ///
+    /// The program contains the same facts and externals as added by [`Instance::add_to`].
+    ///
+    /// # Returns
+    /// - `Ok(String)` containing the program.
+    ///
+    /// # Errors
+    /// - `Err(anyhow::Error)` if a fact is invalid.
+    pub fn to_text(&self) -> Result<String> {
+        let mut asp = String::new();
+
+        for entry in self.entries() {
+            match entry {
+                Entry::Fact(fact) => writeln!(asp, "{}", fact.render()?)?,
+                Entry::External(atom, value) => {
+                    writeln!(asp, "#external {}. [{value}]", atom.atom()?)?
+                }
+            }
+        }
+
+        Ok(asp)
+    }
+
+    /// Iterates over the facts and external atoms of the instance.
+    fn entries(&self) -> impl Iterator<Item = Entry<'_>> {
+        let facts = self.facts.iter().map(Entry::Fact);
+        let externals = self
+            .externals
+            .iter()
+            .map(|external| Entry::External(external, true));
+        // Requirements are enabled by assumption when solving.
+        let requirements = self
+            .requirements
+            .iter()
+            .map(|requirement| Entry::External(requirement, false));
+
+        facts.chain(externals).chain(requirements)
+    }
+}
+
+/// An entry of a problem instance.
+enum Entry<'a> {
+    /// A fact.
+    Fact(&'a Fact),
+    /// An external atom together with its initial truth value.
+    External(&'a Fact, bool),
+}
+
+/// Generates the item related facts.
 ///
 /// # Parameters
-/// - `items`: A slice of `Arc<Item>` representing the items to generate atoms for.
+/// - `items`: A slice of `Arc<Item>` representing the items to generate facts for.
 ///
 /// # Returns
-/// - `Ok(String)` containing the generated ASP atoms if successful.
-///
-/// # Errors
-/// - `Err(anyhow::Error)` if an error occurs during atom generation.
-pub fn item_atoms(items: &[Arc<Item>]) -> Result<String> {
-    let mut asp = String::new();
-    writeln!(asp, "% --- AVAILABLE ITEMS ---")?;
+/// - The `item/3` and `item_bonus/3` facts of the items.
+pub fn item_facts(items: &[Arc<Item>]) -> Vec<Fact> {
+    let mut facts = Vec::new();
 
     for item in items {
-        writeln!(
-            asp,
-            "item({}, {}, \"{}\").",
-            item.id,
-            item.item_slot.name(),
-            item.name
-        )?;
+        facts.push(
+            Fact::new("item")
+                .arg(item.id)
+                .arg(Term::constant(item.item_slot.name()))
+                .arg(item.name.as_str()),
+        );
 
         for bonus in &item.bonuses {
-            let stat_name = bonus.stat.to_string().to_lowercase();
-            writeln!(
-                asp,
-                "item_bonus({}, {}, {}).",
-                item.id, stat_name, bonus.value
-            )?;
+            facts.push(
+                Fact::new("item_bonus")
+                    .arg(item.id)
+                    .arg(Term::constant(bonus.stat.to_string().to_lowercase()))
+                    .arg(bonus.value),
+            );
+        }
+    }
+
+    facts
+}
+
+/// Generates the weapon slot facts of the items.
+///
+/// Which weapon slots an item fits depends on its type, its hand and the class,
+/// see [`Item::weapon_slots`].
+///
+/// # Parameters
+/// - `class`: The character class of the template.
+/// - `items`: A slice of `Arc<Item>` representing the items to generate facts for.
+///
+/// # Returns
+/// - The `weapon_fits/2` facts of the items.
+pub fn weapon_facts(class: Class, items: &[Arc<Item>]) -> Vec<Fact> {
+    let mut facts = Vec::new();
+
+    for item in items {
+        let mut slots = item.weapon_slots(class).into_iter().collect::<Vec<_>>();
+        slots.sort_by_key(ItemSlot::id);
+
+        for slot in slots {
+            facts.push(Fact::new("weapon_fits").arg(item.id).arg(slot.id()));
         }
     }
-    Ok(asp)
+
+    facts
 }
 
-/// Generates stat related ASP atoms.
+/// Generates the external `available/1` atoms of the items.
+///
+/// Every item is declared as an external atom that is true by default,
+/// so items can be enabled or disabled between solve calls without grounding again.
+///
+/// # Parameters
+/// - `items`: A slice of `Arc<Item>` representing the items to generate atoms for.
+///
+/// # Returns
+/// - The `available/1` atoms of the items.
+pub fn available_externals(items: &[Arc<Item>]) -> Vec<Fact> {
+    items
+        .iter()
+        .map(|item| Fact::new("available").arg(item.id))
+        .collect()
+}
+
+/// Generates the external atom of a stat requirement.
+///
+/// # Parameters
+/// - `requirement`: The requirement to generate the atom for.
 ///
 /// # Returns
-/// - `Ok(String)` containing the generated ASP atoms if successful.
+/// - The `require_min/2` or `require_cap/1` atom of the requirement.
+pub fn requirement_external(requirement: &StatRequirement) -> Fact {
+    match requirement {
+        StatRequirement::AtLeast(stat, value) => Fact::new("require_min")
+            .arg(Term::constant(stat.name()))
+            .arg(*value),
+        StatRequirement::Capped(stat) => Fact::new("require_cap").arg(Term::constant(stat.name())),
+    }
+}
+
+/// Generates the stat related facts.
 ///
-/// # Errors
-/// - `Err(anyhow::Error)` if an error occurs during atom generation.
-pub fn stat_atoms() -> Result<String> {
-    let mut asp = String::new();
-    writeln!(asp, "% --- STATS ---")?;
+/// # Parameters
+/// - `weights`: The utility weights per stat point, emitted as the utility of `stat/3`.
+/// - `priorities`: The priority levels of the stat groups, emitted as `stat_priority/2`.
+///
+/// # Returns
+/// - The `stat/3`, `stat_priority/2`, `stat_cap/2` and `stat_base/2` facts.
+pub fn stat_facts(weights: &WeightProfile, priorities: &PriorityProfile) -> Vec<Fact> {
+    let mut facts = Vec::new();
+
     for stat in Stat::iter() {
-        writeln!(
-            asp,
-            "stat({}, {}, {}).",
-            stat.name(),
-            (stat.utility_per_point() * 100.0).round() as i32,
-            stat.cap()
-        )?;
+        let utility = (weights.weight(stat) * 100.0).round() as i32;
+        facts.push(
+            Fact::new("stat")
+                .arg(Term::constant(stat.name()))
+                .arg(utility)
+                .arg(stat.cap()),
+        );
+        facts.push(
+            Fact::new("stat_priority")
+                .arg(Term::constant(stat.name()))
+                .arg(priorities.level_of(stat)),
+        );
 
         if let Some(cap) = stat.cap_stat() {
-            writeln!(asp, "stat_cap({}, {}).", stat.name(), cap.name())?;
+            facts.push(
+                Fact::new("stat_cap")
+                    .arg(Term::constant(stat.name()))
+                    .arg(Term::constant(cap.name())),
+            );
         }
 
         if let Some(base) = stat.base_stat() {
-            writeln!(asp, "stat_base({}, {}).", stat.name(), base.name())?;
+            facts.push(
+                Fact::new("stat_base")
+                    .arg(Term::constant(stat.name()))
+                    .arg(Term::constant(base.name())),
+            );
         }
     }
-    Ok(asp)
+
+    facts
 }
 
-/// Generates class related ASP atoms.
+/// Generates the class related facts.
 ///
 /// # Parameters
-/// - `class`: A `Class` representing the character class to generate atoms for.
+/// - `class`: A `Class` representing the character class to generate facts for.
 ///
 /// # Returns
-/// - `Ok(String)` containing the generated ASP atoms if successful.
-///
-/// # Errors
-/// - `Err(anyhow::Error)` if an error occurs during atom generation.
-pub fn class_atoms(class: Class) -> Result<String> {
-    let mut asp = String::new();
-    writeln!(asp, "% --- CLASS ---")?;
-
-    writeln!(asp, "class({}).", class.to_string().to_lowercase())?;
+/// - The `class/1`, `class_skill_line/1` and `class_acuity/1` facts.
+pub fn class_facts(class: Class) -> Vec<Fact> {
+    let mut facts = vec![Fact::new("class").arg(Term::constant(class.to_string().to_lowercase()))];
 
     for line in class.skill_lines() {
-        writeln!(asp, "class_skill_line({}).", line)?;
+        facts.push(Fact::new("class_skill_line").arg(Term::constant(line.to_string())));
     }
 
     if let Some(acuity) = class.acuity_stat() {
-        writeln!(asp, "class_acuity({}).", acuity)?;
+        facts.push(Fact::new("class_acuity").arg(Term::constant(acuity.to_string())));
     }
 
-    Ok(asp)
+    facts
 }
 
-/// Generates item slot related ASP atoms.
+/// Generates the item slot related facts.
 ///
-/// # Parameters
-/// - `template`: A reference to a `Template` containing the item slots to generate atoms for.
+/// Items placed in slots by the user are not part of the instance,
+/// they are passed as assumptions on `slot_taken/2` when solving.
 ///
 /// # Returns
-/// - `Ok(String)` containing the generated ASP atoms if successful.
-///
-/// # Errors
-/// - `Err(anyhow::Error)` if an error occurs during atom generation.
-pub fn slot_atoms(template: &Template) -> Result<String> {
-    let mut asp = String::new();
-    writeln!(asp, "% --- TEMPLATE ---")?;
-
-    for slot in ItemSlot::iter() {
-        writeln!(asp, "slot({},{}).", slot.id(), slot.name())?;
-
-        if let Some(item) = template.slots.get(&slot) {
-            writeln!(asp, "slot_taken({}, {}).", slot.id(), item.id)?;
-        }
-    }
-
-    Ok(asp)
+/// - The `slot/2` facts.
+pub fn slot_facts() -> Vec<Fact> {
+    ItemSlot::iter()
+        .map(|slot| {
+            Fact::new("slot")
+                .arg(slot.id())
+                .arg(Term::constant(slot.name()))
+        })
+        .collect()
 }
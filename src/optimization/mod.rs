@@ -1,6 +1,12 @@
 //! This module provides the functionality for template optimization using ASP.
 //!
-//! It includes instance generation, encoding and the optimization worker that manages the optimization process.
+//! It includes instance generation, encoding and the optimization session that manages the optimization process.
 
+pub mod assumption;
+pub mod encoding;
+pub mod fact;
+pub mod inspection;
 pub mod instance;
+pub mod session;
+pub mod statistics;
 pub mod worker;
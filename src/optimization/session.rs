@@ -0,0 +1,771 @@
+//! This module provides the long-lived optimization session of a template.
+//!
+//! A session grounds the problem instance of a template once and keeps the grounded
+//! program alive in its own thread. Pinning, unpinning and excluding items only changes
+//! assumptions and external atoms, so a re-solve starts without grounding again.
+//! The session is controlled through [`SessionCommand`]s and reports back through
+//! [`OptimizeStatus`] updates. Found models are reported from the solve event callback,
+//! so the session thread only wakes up for commands and when a solve call finishes.
+
+use crate::clingo::configuration::ParallelMode;
+use crate::clingo::control::{Control, SolveEvent};
+use crate::clingo::model::Model;
+use crate::clingo::solve_result::SolveResult;
+use crate::core::config::Config;
+use crate::core::domain::{
+    item::Item, item_slot::ItemSlot, priority_profile::PriorityProfile,
+    stat_requirement::StatRequirement, template::Template, weight_profile::WeightProfile,
+};
+use crate::optimization::assumption::{
+    Assumption, assumption_literals, core_assumptions, set_item_availability,
+};
+use crate::optimization::encoding::load_encoding;
+use crate::optimization::inspection::summarize_instance;
+use crate::optimization::instance::Instance;
+use crate::optimization::statistics::SolveStatistics;
+use crate::optimization::worker::{OptimizeStatus, Solution, SolveLimit, template_from_model};
+use anyhow::{Context, Result, anyhow};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::mpsc::{Receiver, RecvTimeoutError, Sender, channel};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc::UnboundedSender;
+
+/// Commands that control an optimization session.
+#[derive(Debug, Clone)]
+pub enum SessionCommand {
+    /// Places an item in a slot for all following solve calls.
+    PinItem {
+        /// The slot the item is placed in.
+        slot: ItemSlot,
+        /// The ID of the item.
+        item_id: i32,
+    },
+    /// Frees a slot again, so the optimization may choose its item.
+    UnpinItem {
+        /// The slot to free.
+        slot: ItemSlot,
+    },
+    /// Prevents an item from being chosen.
+    ExcludeItem {
+        /// The ID of the item.
+        item_id: i32,
+    },
+    /// Allows a previously excluded item to be chosen again.
+    IncludeItem {
+        /// The ID of the item.
+        item_id: i32,
+    },
+    /// Replaces the weight profile of the template.
+    ///
+    /// The weights are part of the ground program, so this grounds the instance again.
+    SetWeights(WeightProfile),
+    /// Replaces the stat requirements of the template.
+    ///
+    /// The requirements are external atoms of the ground program, so this grounds the instance again.
+    SetRequirements(Vec<StatRequirement>),
+    /// Replaces the priority levels of the template.
+    ///
+    /// The priorities are part of the ground program, so this grounds the instance again.
+    SetPriorities(PriorityProfile),
+    /// Starts solving with the current pins and exclusions.
+    Solve,
+    /// Stops the running solve call, the session stays alive.
+    Stop,
+}
+
+/// Messages processed by an optimization session.
+#[derive(Debug)]
+pub enum SessionEvent {
+    /// A command sent through the session handle.
+    Command(SessionCommand),
+    /// The running solve call has finished.
+    SolveFinished,
+    /// The session handle was dropped, the session shuts down.
+    Shutdown,
+}
+
+/// A handle to send commands to a running optimization session.
+///
+/// The session shuts down once its handle is dropped.
+pub struct SessionHandle {
+    events: Sender<SessionEvent>,
+}
+
+impl SessionHandle {
+    /// Sends a command to the session.
+    ///
+    /// # Parameters
+    /// - `command`: The command to send.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the command was sent.
+    ///
+    /// # Errors
+    /// - `Err(anyhow::Error)` if the session is no longer running.
+    pub fn send(&self, command: SessionCommand) -> Result<()> {
+        self.events
+            .send(SessionEvent::Command(command))
+            .map_err(|_| anyhow!("The optimization session is no longer running"))
+    }
+}
+
+impl Drop for SessionHandle {
+    fn drop(&mut self) {
+        let _ = self.events.send(SessionEvent::Shutdown);
+    }
+}
+
+/// Starts an optimization session for a template in a separate thread.
+///
+/// The session immediately grounds the problem instance and reports `Ready` when
+/// it accepts commands. The items currently placed in the template are pinned.
+///
+/// # Parameters
+/// - `template`: The template to optimize.
+/// - `items`: The available items to consider during optimization.
+/// - `config`: The application configuration, used to configure the solver.
+/// - `status_sender`: An unbounded sender for sending optimization status updates back to the main thread.
+///
+/// # Returns
+/// - A `SessionHandle` to send commands to the session.
+pub fn start_optimization_session(
+    template: Template,
+    items: Vec<Arc<Item>>,
+    config: Arc<Config>,
+    status_sender: UnboundedSender<OptimizeStatus>,
+) -> SessionHandle {
+    let (event_sender, event_receiver) = channel();
+    let session_sender = event_sender.clone();
+
+    thread::spawn(move || {
+        match OptimizationSession::new(
+            template,
+            items,
+            config,
+            status_sender.clone(),
+            session_sender,
+        ) {
+            Ok(mut session) => session.run(&event_receiver),
+            Err(e) => {
+                let _ = status_sender.send(OptimizeStatus::Error(e.to_string()));
+            }
+        }
+    });
+
+    SessionHandle {
+        events: event_sender,
+    }
+}
+
+/// An optimization session holding the grounded program of a template.
+pub struct OptimizationSession {
+    control: Control,
+    template: Template,
+    items: Vec<Arc<Item>>,
+    config: Arc<Config>,
+    status_sender: UnboundedSender<OptimizeStatus>,
+    event_sender: Sender<SessionEvent>,
+    pins: HashMap<ItemSlot, i32>,
+    excluded_items: HashSet<i32>,
+    ground_time: Duration,
+}
+
+impl OptimizationSession {
+    /// Creates a new session and grounds the problem instance of the template.
+    ///
+    /// # Parameters
+    /// - `template`: The template to optimize, its items become the initial pins.
+    /// - `items`: The available items to consider during optimization.
+    /// - `config`: The application configuration, used to configure the solver.
+    /// - `status_sender`: An unbounded sender for sending optimization status updates back to the main thread.
+    /// - `event_sender`: A sender to the event channel of the session, used by the solve event callback.
+    ///
+    /// # Returns
+    /// - `Ok(OptimizationSession)` if the instance was grounded successfully.
+    ///
+    /// # Errors
+    /// - `Err(anyhow::Error)` if the instance could not be generated or grounded.
+    pub fn new(
+        template: Template,
+        items: Vec<Arc<Item>>,
+        config: Arc<Config>,
+        status_sender: UnboundedSender<OptimizeStatus>,
+        event_sender: Sender<SessionEvent>,
+    ) -> Result<Self> {
+        let pins = template
+            .slots
+            .iter()
+            .map(|(slot, item)| (*slot, item.id))
+            .collect();
+        let (control, ground_time) = Self::ground(&template, &items, &config, &status_sender)?;
+        let _ = status_sender.send(OptimizeStatus::Ready);
+
+        Ok(OptimizationSession {
+            control,
+            template,
+            items,
+            config,
+            status_sender,
+            event_sender,
+            pins,
+            excluded_items: HashSet::new(),
+            ground_time,
+        })
+    }
+
+    /// Processes events until the session handle is dropped.
+    ///
+    /// Errors of single commands are reported as `OptimizeStatus::Error`
+    /// and do not end the session.
+    ///
+    /// # Parameters
+    /// - `events`: The receiver of the session events.
+    pub fn run(&mut self, events: &Receiver<SessionEvent>) {
+        let mut pending = VecDeque::new();
+
+        loop {
+            let event = match pending.pop_front() {
+                Some(event) => event,
+                None => match events.recv() {
+                    Ok(event) => event,
+                    Err(_) => return,
+                },
+            };
+
+            let result = match event {
+                SessionEvent::Command(SessionCommand::Solve) => self.solve(events, &mut pending),
+                SessionEvent::Command(command) => self.apply(command),
+                SessionEvent::SolveFinished => Ok(()),
+                SessionEvent::Shutdown => return,
+            };
+
+            if let Err(e) = result {
+                let _ = self
+                    .status_sender
+                    .send(OptimizeStatus::Error(e.to_string()));
+            }
+        }
+    }
+
+    /// Applies a command that changes the state of the session.
+    fn apply(&mut self, command: SessionCommand) -> Result<()> {
+        match command {
+            SessionCommand::PinItem { slot, item_id } => {
+                self.pins.insert(slot, item_id);
+            }
+            SessionCommand::UnpinItem { slot } => {
+                self.pins.remove(&slot);
+            }
+            SessionCommand::ExcludeItem { item_id } => {
+                if self.excluded_items.insert(item_id) {
+                    set_item_availability(&self.control, item_id, false)?;
+                }
+            }
+            SessionCommand::IncludeItem { item_id } => {
+                if self.excluded_items.remove(&item_id) {
+                    set_item_availability(&self.control, item_id, true)?;
+                }
+            }
+            SessionCommand::SetWeights(weights) => {
+                self.template.weights = weights;
+                self.reground()?;
+            }
+            SessionCommand::SetRequirements(requirements) => {
+                self.template.requirements = requirements;
+                self.reground()?;
+            }
+            SessionCommand::SetPriorities(priorities) => {
+                self.template.priorities = priorities;
+                self.reground()?;
+            }
+            SessionCommand::Solve | SessionCommand::Stop => {}
+        }
+
+        Ok(())
+    }
+
+    /// Grounds the instance of the template again and restores the excluded items.
+    fn reground(&mut self) -> Result<()> {
+        (self.control, self.ground_time) = Self::ground(
+            &self.template,
+            &self.items,
+            &self.config,
+            &self.status_sender,
+        )?;
+        for item_id in &self.excluded_items {
+            set_item_availability(&self.control, *item_id, false)?;
+        }
+        let _ = self.status_sender.send(OptimizeStatus::Ready);
+
+        Ok(())
+    }
+
+    /// Solves with the current pins and stat requirements and streams the found models.
+    ///
+    /// Events arriving while solving are queued and processed afterwards,
+    /// except for `Stop`, which cancels the solve call. If a time budget or a limit
+    /// on models or time without improvement is configured, reaching it cancels the solve
+    /// call as well and reports the best model found so far as `TimedOut`.
+    /// Every solve call ends with a terminal status built from clingo's solve result.
+    fn solve(
+        &mut self,
+        events: &Receiver<SessionEvent>,
+        pending: &mut VecDeque<SessionEvent>,
+    ) -> Result<()> {
+        let _ = self.status_sender.send(OptimizeStatus::Solving);
+
+        let assumptions = self
+            .pins
+            .iter()
+            .map(|(slot, item_id)| Assumption::PinItem {
+                slot: *slot,
+                item_id: *item_id,
+            })
+            .chain(
+                self.template
+                    .requirements
+                    .iter()
+                    .map(|requirement| Assumption::Require(*requirement)),
+            )
+            .collect::<Vec<_>>();
+        let atoms = self.control.symbolic_atoms()?;
+        let literals = assumption_literals(&assumptions, &atoms)?;
+
+        let progress = Arc::new(Mutex::new(SolveProgress::default()));
+        let limits = SolveLimits::from_config(&self.config);
+        let started = Instant::now();
+        let mut cancelled = false;
+
+        let mut handle = self
+            .control
+            .solve_with_callback(&literals, self.solve_callback(progress.clone()))?;
+
+        loop {
+            let next_limit = if cancelled {
+                None
+            } else {
+                progress
+                    .lock()
+                    .ok()
+                    .and_then(|progress| progress.next_limit(started, &limits))
+            };
+
+            let event = match next_limit {
+                Some((deadline, _)) => {
+                    events.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                }
+                None => events.recv().map_err(RecvTimeoutError::from),
+            };
+
+            match event {
+                Ok(SessionEvent::Command(SessionCommand::Stop)) => {
+                    cancelled = true;
+                    handle.cancel()?;
+                }
+                Ok(SessionEvent::SolveFinished) => break,
+                Err(RecvTimeoutError::Timeout) => {
+                    // A model may have improved the cost while waiting, which moves the deadline.
+                    let Ok(mut progress) = progress.lock() else {
+                        continue;
+                    };
+                    if let Some((deadline, limit)) = progress.next_limit(started, &limits)
+                        && deadline <= Instant::now()
+                    {
+                        progress.limit.get_or_insert(limit);
+                        drop(progress);
+                        cancelled = true;
+                        handle.cancel()?;
+                    }
+                }
+                Ok(SessionEvent::Shutdown) | Err(RecvTimeoutError::Disconnected) => {
+                    handle.cancel()?;
+                    pending.push_back(SessionEvent::Shutdown);
+                    return Ok(());
+                }
+                Ok(event) => pending.push_back(event),
+            }
+        }
+
+        let result = handle.get()?;
+        let core = if result.contains(SolveResult::UNSATISFIABLE) {
+            core_assumptions(&assumptions, &atoms, &handle.core()?)?
+        } else {
+            Vec::new()
+        };
+        drop(handle);
+        send_messages(&self.control, &self.status_sender);
+        self.send_statistics()?;
+
+        let progress = std::mem::take(
+            &mut *progress
+                .lock()
+                .map_err(|_| anyhow!("Solve progress mutex was poisoned"))?,
+        );
+        self.status_sender
+            .send(outcome_status(result, progress.limit, progress.best, core))?;
+
+        Ok(())
+    }
+
+    /// Creates the solve event callback of a solve call.
+    ///
+    /// The callback runs in the solver thread. It reports found models directly,
+    /// records them in the progress of the solve call and notifies the session
+    /// once the search has finished.
+    fn solve_callback(
+        &self,
+        progress: Arc<Mutex<SolveProgress>>,
+    ) -> impl FnMut(SolveEvent<'_>) -> bool + Send + 'static {
+        let template = self.template.clone();
+        let items = self.items.clone();
+        let status_sender = self.status_sender.clone();
+        let event_sender = self.event_sender.clone();
+        let max_unimproved_models = self.config.optimization.max_unimproved_models;
+
+        move |event| match event {
+            SolveEvent::Model(model) => match solution_from_model(*model, &template, &items) {
+                Ok(solution) => {
+                    let _ = status_sender.send(OptimizeStatus::NewModel(solution.clone()));
+                    progress.lock().map_or(true, |mut progress| {
+                        progress.record(solution, max_unimproved_models, Instant::now())
+                    })
+                }
+                Err(e) => {
+                    let _ = status_sender.send(OptimizeStatus::Error(e.to_string()));
+                    true
+                }
+            },
+            SolveEvent::Finish(_) => {
+                let _ = event_sender.send(SessionEvent::SolveFinished);
+                true
+            }
+            SolveEvent::Unsat | SolveEvent::Statistics => true,
+        }
+    }
+
+    /// Reports the statistics of the last solve call.
+    ///
+    /// The solve handle has to be closed before, clingo does not provide statistics
+    /// while solving.
+    fn send_statistics(&self) -> Result<()> {
+        let tree = self.control.statistics()?.tree()?;
+        let statistics = SolveStatistics::from_tree(&tree, self.ground_time);
+        self.status_sender
+            .send(OptimizeStatus::Statistics(statistics))?;
+
+        Ok(())
+    }
+
+    /// Creates a control holding the grounded problem instance.
+    ///
+    /// Returns the control together with the time spent grounding.
+    fn ground(
+        template: &Template,
+        items: &[Arc<Item>],
+        config: &Config,
+        status_sender: &UnboundedSender<OptimizeStatus>,
+    ) -> Result<(Control, Duration)> {
+        let _ = status_sender.send(OptimizeStatus::Setup);
+
+        let instance = Instance::new(template, items);
+
+        if let Some(path) = &config.optimization.instance_debug_path {
+            std::fs::write(path, instance.to_text()?)
+                .with_context(|| format!("Failed to write debug instance to '{path}'"))?;
+        }
+
+        let encoding = load_encoding(config.optimization.encoding_path.as_deref())?;
+
+        let mut args = vec!["--stats".to_owned()];
+        args.extend(config.optimization.clingo_args.iter().cloned());
+
+        let mut control = Control::with_args(&args)?;
+        if let Some(threads) = solver_threads(config) {
+            if sets_parallel_mode(&config.optimization.clingo_args) {
+                println!(
+                    "Warning: optimization.threads overrides the parallel mode given in clingo_args."
+                );
+            }
+            control
+                .configuration_mut()?
+                .set_parallel_mode(threads, ParallelMode::Compete)?;
+        }
+        control.add("base", &[], &encoding)?;
+        instance.add_to(&control)?;
+
+        let _ = status_sender.send(OptimizeStatus::Grounding);
+        let start = Instant::now();
+        control.ground(&[("base", vec![])])?;
+        let ground_time = start.elapsed();
+        send_messages(&control, status_sender);
+
+        let status = match summarize_instance(&control, items) {
+            Ok(summary) => OptimizeStatus::Instance(summary),
+            Err(e) => OptimizeStatus::Error(format!("Failed to inspect the instance: {e}")),
+        };
+        let _ = status_sender.send(status);
+
+        Ok((control, ground_time))
+    }
+}
+
+/// Forwards the messages clingo reported since the last call to the UI.
+fn send_messages(control: &Control, status_sender: &UnboundedSender<OptimizeStatus>) {
+    let messages = control.messages();
+    if !messages.is_empty() {
+        let _ = status_sender.send(OptimizeStatus::Messages(messages));
+    }
+}
+
+/// The maximum number of solver threads clingo supports.
+const MAX_SOLVER_THREADS: usize = 64;
+
+/// Determines the number of solver threads from the configuration.
+///
+/// Returns `None` if no thread count is configured, so clingo keeps the parallel mode
+/// given in `clingo_args` or solves with a single thread. A thread count of `0` uses
+/// one thread per available core.
+fn solver_threads(config: &Config) -> Option<u32> {
+    let threads = match config.optimization.threads? {
+        0 => thread::available_parallelism().map_or(1, |threads| threads.get()),
+        threads => threads,
+    };
+
+    Some(threads.min(MAX_SOLVER_THREADS) as u32)
+}
+
+/// Checks whether clingo command-line arguments set the parallel mode.
+fn sets_parallel_mode(args: &[String]) -> bool {
+    args.iter()
+        .any(|arg| arg.starts_with("-t") || arg.starts_with("--parallel-mode"))
+}
+
+/// Builds the solution represented by a found model.
+fn solution_from_model(model: Model, base: &Template, items: &[Arc<Item>]) -> Result<Solution> {
+    let cost = model.cost()?;
+    let priorities = model.priority()?;
+    let optimal = model.optimality_proven()?;
+    let template = template_from_model(model, base, items)?;
+
+    Ok(Solution {
+        template,
+        cost,
+        priorities,
+        optimal,
+    })
+}
+
+/// Builds the terminal status of a solve call.
+///
+/// A proven result takes precedence over a limit that was reached at the same time.
+fn outcome_status(
+    result: SolveResult,
+    limit: Option<SolveLimit>,
+    best: Option<Solution>,
+    core: Vec<Assumption>,
+) -> OptimizeStatus {
+    if result.contains(SolveResult::UNSATISFIABLE) {
+        OptimizeStatus::Unsatisfiable { result, best, core }
+    } else if result.contains(SolveResult::EXHAUSTED) {
+        OptimizeStatus::Exhausted { result, best }
+    } else if let Some(limit) = limit {
+        OptimizeStatus::TimedOut {
+            limit,
+            result,
+            best,
+        }
+    } else {
+        OptimizeStatus::Cancelled { result, best }
+    }
+}
+
+/// The limits of a solve call taken from the configuration.
+#[derive(Debug, Clone, Copy, Default)]
+struct SolveLimits {
+    /// The time budget of the whole solve call.
+    time: Option<Duration>,
+    /// The time the search may run without improving the cost.
+    unimproved_time: Option<Duration>,
+}
+
+impl SolveLimits {
+    /// Reads the limits from the configuration.
+    fn from_config(config: &Config) -> Self {
+        SolveLimits {
+            time: config.optimization.time_limit_secs.map(Duration::from_secs),
+            unimproved_time: config
+                .optimization
+                .max_unimproved_secs
+                .map(Duration::from_secs),
+        }
+    }
+}
+
+/// The progress of a solve call, shared with its solve event callback.
+#[derive(Debug, Default)]
+struct SolveProgress {
+    best: Option<Solution>,
+    unimproved_models: u32,
+    last_improvement: Option<Instant>,
+    limit: Option<SolveLimit>,
+}
+
+impl SolveProgress {
+    /// Records a found model and checks the limit on models without improvement.
+    ///
+    /// Under clingo's default optimization mode every reported model improves the cost,
+    /// so models without improvement only occur when enumerating, e.g. with `--opt-mode=optN`.
+    ///
+    /// # Parameters
+    /// - `solution`: The solution of the found model.
+    /// - `max_unimproved_models`: The number of models in a row without improvement that stops the search.
+    /// - `now`: The time the model was found.
+    ///
+    /// # Returns
+    /// - `false` if the limit was reached and the search should stop.
+    fn record(
+        &mut self,
+        solution: Solution,
+        max_unimproved_models: Option<u32>,
+        now: Instant,
+    ) -> bool {
+        let improved = self
+            .best
+            .as_ref()
+            .is_none_or(|best| solution.cost < best.cost || (solution.optimal && !best.optimal));
+
+        if improved {
+            self.best = Some(solution);
+            self.unimproved_models = 0;
+            self.last_improvement = Some(now);
+            return true;
+        }
+
+        self.unimproved_models += 1;
+        match max_unimproved_models {
+            Some(limit) if self.unimproved_models >= limit => {
+                self.limit = Some(SolveLimit::UnimprovedModels(limit));
+                false
+            }
+            _ => true,
+        }
+    }
+
+    /// Determines the time limit that is reached first.
+    ///
+    /// The time without improvement counts from the last improving model,
+    /// or from the start of the solve call if no model was found yet.
+    ///
+    /// # Parameters
+    /// - `started`: The start of the solve call.
+    /// - `limits`: The configured limits.
+    ///
+    /// # Returns
+    /// - The deadline and the limit reached at it, `None` if no time limit is configured.
+    fn next_limit(&self, started: Instant, limits: &SolveLimits) -> Option<(Instant, SolveLimit)> {
+        let time = limits
+            .time
+            .map(|limit| (started + limit, SolveLimit::Time(limit)));
+        let unimproved_time = limits.unimproved_time.map(|limit| {
+            (
+                self.last_improvement.unwrap_or(started) + limit,
+                SolveLimit::UnimprovedTime(limit),
+            )
+        });
+
+        time.into_iter()
+            .chain(unimproved_time)
+            .min_by_key(|(deadline, _)| *deadline)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::class::Class;
+
+    fn solution(cost: i64) -> Solution {
+        Solution {
+            template: Template::new(Class::Armsman),
+            cost: vec![cost],
+            priorities: vec![0],
+            optimal: false,
+        }
+    }
+
+    #[test]
+    fn record_counts_models_without_improvement() {
+        let now = Instant::now();
+        let mut progress = SolveProgress::default();
+
+        assert!(progress.record(solution(-10), Some(2), now));
+        assert!(progress.record(solution(-20), Some(2), now));
+        assert_eq!(progress.unimproved_models, 0);
+
+        assert!(progress.record(solution(-20), Some(2), now));
+        assert!(!progress.record(solution(-15), Some(2), now));
+        assert_eq!(progress.limit, Some(SolveLimit::UnimprovedModels(2)));
+        assert_eq!(progress.best.map(|best| best.cost), Some(vec![-20]));
+    }
+
+    #[test]
+    fn next_limit_counts_from_last_improvement() {
+        let started = Instant::now();
+        let limits = SolveLimits {
+            time: Some(Duration::from_secs(60)),
+            unimproved_time: Some(Duration::from_secs(10)),
+        };
+        let mut progress = SolveProgress::default();
+
+        assert_eq!(
+            progress.next_limit(started, &limits),
+            Some((
+                started + Duration::from_secs(10),
+                SolveLimit::UnimprovedTime(Duration::from_secs(10))
+            ))
+        );
+
+        progress.record(solution(-10), None, started + Duration::from_secs(55));
+        assert_eq!(
+            progress.next_limit(started, &limits),
+            Some((
+                started + Duration::from_secs(60),
+                SolveLimit::Time(Duration::from_secs(60))
+            ))
+        );
+
+        assert_eq!(
+            SolveProgress::default().next_limit(started, &SolveLimits::default()),
+            None
+        );
+    }
+
+    #[test]
+    fn outcome_status_prefers_proven_results_over_limits() {
+        let limit = Some(SolveLimit::Time(Duration::from_secs(1)));
+
+        assert!(matches!(
+            outcome_status(SolveResult::UNSATISFIABLE, limit, None, Vec::new()),
+            OptimizeStatus::Unsatisfiable { .. }
+        ));
+        assert!(matches!(
+            outcome_status(
+                SolveResult::SATISFIABLE | SolveResult::EXHAUSTED,
+                limit,
+                Some(solution(-1)),
+                Vec::new()
+            ),
+            OptimizeStatus::Exhausted { best: Some(_), .. }
+        ));
+        assert!(matches!(
+            outcome_status(SolveResult::INTERRUPTED, limit, None, Vec::new()),
+            OptimizeStatus::TimedOut { .. }
+        ));
+        assert!(matches!(
+            outcome_status(SolveResult::INTERRUPTED, None, None, Vec::new()),
+            OptimizeStatus::Cancelled { .. }
+        ));
+    }
+}
@@ -0,0 +1,55 @@
+//! This module provides a summary of the solver statistics of an optimization run.
+//!
+//! The summary separates the time spent grounding from the time spent searching,
+//! which helps to tell where the encoding needs tuning.
+
+use crate::clingo::statistics::StatisticsEntry;
+use std::time::Duration;
+
+/// The statistics of a single optimization run.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SolveStatistics {
+    /// The time spent grounding the problem instance.
+    pub ground_time: Duration,
+    /// The time spent searching for models.
+    pub solve_time: Duration,
+    /// The number of models found.
+    pub models: u64,
+    /// The number of choices made by the solver.
+    pub choices: u64,
+    /// The number of conflicts encountered by the solver.
+    pub conflicts: u64,
+    /// The number of rules in the ground program.
+    pub rules: u64,
+    /// The number of atoms in the ground program.
+    pub atoms: u64,
+}
+
+impl SolveStatistics {
+    /// Builds the summary from the statistics tree of a control.
+    ///
+    /// Entries missing from the tree are reported as zero. The solver and program
+    /// counters are only present if the control was created with `--stats`.
+    ///
+    /// # Parameters
+    /// - `tree`: The root of the statistics tree.
+    /// - `ground_time`: The measured time spent grounding, clingo does not report it.
+    ///
+    /// # Returns
+    /// - The summary of the statistics.
+    pub fn from_tree(tree: &StatisticsEntry, ground_time: Duration) -> Self {
+        let count = |path: &str| tree.value(path).unwrap_or(0.0) as u64;
+
+        SolveStatistics {
+            ground_time,
+            solve_time: Duration::from_secs_f64(
+                tree.value("summary.times.solve").unwrap_or(0.0).max(0.0),
+            ),
+            models: count("summary.models.enumerated"),
+            choices: count("solving.solvers.choices"),
+            conflicts: count("solving.solvers.conflicts"),
+            rules: count("problem.lp.rules"),
+            atoms: count("problem.lp.atoms"),
+        }
+    }
+}
@@ -1,148 +1,144 @@
-//! This module provides the worker that manages the optimization process.
+//! This module provides the status updates of the optimization process
+//! and the translation of models back into templates.
+//!
+//! The optimization itself runs in an [`OptimizationSession`](crate::optimization::session::OptimizationSession).
 
-use crate::clingo::control::Control;
+use crate::clingo::logger::LogMessage;
 use crate::clingo::model::Model;
+use crate::clingo::solve_result::SolveResult;
 use crate::clingo::symbol::SymbolType;
 use crate::core::domain::item_slot::ItemSlot;
 use crate::core::domain::{item::Item, template::Template};
-use crate::optimization::instance::{class_atoms, item_atoms, slot_atoms, stat_atoms};
+use crate::optimization::assumption::Assumption;
+use crate::optimization::inspection::InstanceSummary;
+use crate::optimization::statistics::SolveStatistics;
 use anyhow::{Context, Result, anyhow};
-use std::thread;
-use std::{
-    path::Path,
-    sync::{
-        Arc,
-        atomic::{AtomicBool, Ordering},
-    },
-};
-use tokio::sync::mpsc::UnboundedSender;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// A template found by the solver together with its quality.
+#[derive(Debug, Clone)]
+pub struct Solution {
+    /// The template represented by the model.
+    pub template: Template,
+    /// The cost of the model per optimization level, highest priority first.
+    pub cost: Vec<i64>,
+    /// The priority level of each entry of `cost`.
+    pub priorities: Vec<i32>,
+    /// Whether the model is proven to be optimal or just the best one found so far.
+    pub optimal: bool,
+}
+
+impl Solution {
+    /// Returns the cost of the model paired with the priority level of each entry.
+    pub fn levels(&self) -> Vec<(i32, i64)> {
+        self.priorities
+            .iter()
+            .copied()
+            .zip(self.cost.iter().copied())
+            .collect()
+    }
+}
+
+/// A limit that ended a solve call before the search was exhausted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolveLimit {
+    /// The time budget of the solve call was used up.
+    Time(Duration),
+    /// The given number of models in a row did not improve on the best model.
+    UnimprovedModels(u32),
+    /// The best model did not improve for the given time.
+    UnimprovedTime(Duration),
+}
+
 /// Represents the current status of the optimization process.
 pub enum OptimizeStatus {
     /// Setup of the problem instance and loading of the encoding.
     Setup,
     /// Grounding of the problem instance.
     Grounding,
+    /// A summary of the grounded problem instance, used for debugging the instance generation.
+    Instance(InstanceSummary),
+    /// The problem instance is grounded and the session waits for commands.
+    Ready,
     /// Solving the optimization problem and retrieving models.
     Solving,
     /// A new model has been found during the optimization process.
-    NewModel(Template),
-    /// The optimization process has finished, either because all models have been found or because it was stopped.
-    Finished,
+    NewModel(Solution),
+    /// The solver statistics of the finished or stopped solve call.
+    Statistics(SolveStatistics),
+    /// Messages clingo reported while grounding or solving, such as undefined atoms.
+    Messages(Vec<LogMessage>),
+    /// The search space was exhausted, so the best model is proven optimal.
+    Exhausted {
+        /// The result reported by clingo.
+        result: SolveResult,
+        /// The best model found, if any.
+        best: Option<Solution>,
+    },
+    /// No template satisfies the encoding together with the pinned items.
+    Unsatisfiable {
+        /// The result reported by clingo.
+        result: SolveResult,
+        /// The best model found, `None` unless clingo reported models before.
+        best: Option<Solution>,
+        /// The pins that cannot hold together, empty if no template exists at all.
+        core: Vec<Assumption>,
+    },
+    /// The solve call was stopped by the user, so the best model is not proven optimal.
+    Cancelled {
+        /// The result reported by clingo.
+        result: SolveResult,
+        /// The best model found before the solve call was stopped, if any.
+        best: Option<Solution>,
+    },
+    /// The solve call was stopped by a limit, so the best model is not proven optimal.
+    TimedOut {
+        /// The limit that stopped the solve call.
+        limit: SolveLimit,
+        /// The result reported by clingo.
+        result: SolveResult,
+        /// The best model found before the limit was reached, if any.
+        best: Option<Solution>,
+    },
     /// An error occurred during the optimization process, with a message describing the error.
     Error(String),
 }
 
-/// Starts a worker for the optimization logic in a separate thread.
+/// Builds the template represented by a model.
 ///
-/// This function spawns a new thread that performs the optimization process using the provided template and items.
-/// It communicates the status of the optimization process back to the main thread through the provided `UnboundedSender`.
-/// The worker will check the `stop_flag` periodically to determine if it should stop the optimization process and exit gracefully.
+/// The slots of the resulting template hold exactly the items assigned in the model,
+/// both pinned and chosen ones.
 ///
 /// # Parameters
-/// - `template`: The initial template for the optimization process.
-/// - `items`: A vector of available items to consider during optimization.
-/// - `sender`: An unbounded sender for sending optimization status updates back to the main thread
-/// - `stop_flag`: An atomic boolean flag that can be set to signal the worker to stop the optimization process.
-pub fn start_optimization_worker(
-    template: Template,
-    items: Vec<Arc<Item>>,
-    status_sender: UnboundedSender<OptimizeStatus>,
-    stop_flag: Arc<AtomicBool>,
-) {
-    thread::spawn(move || {
-        // The main thread simply calls the logic and handles the final result
-        if let Err(e) = run_optimization_logic(&template, &items, &status_sender, &stop_flag) {
-            let _ = status_sender.send(OptimizeStatus::Error(e.to_string()));
-        }
-    });
-}
-
-/// The main logic for the optimization process, which is run in a separate thread by `start_optimization_worker`.
-///
-/// # Parameters
-/// - `template`: The initial template for the optimization process.
-/// - `items`: A vector of available items to consider during optimization.
-/// - `sender`: An unbounded sender for sending optimization status updates back to the main thread
-/// - `stop_flag`: An atomic boolean flag that can be set to signal the worker to stop the optimization process.
+/// - `model`: The model to build the template from.
+/// - `base`: The template that was optimized, providing name and class.
+/// - `items`: The available items, used to resolve the item IDs of the model.
 ///
 /// # Returns
-/// - `Ok(())` if the optimization process completed successfully or was stopped gracefully.
-/// - `Err(anyhow::Error)` if an error occurred during the optimization process.
-fn run_optimization_logic(
-    template: &Template,
-    items: &[Arc<Item>],
-    status_sender: &UnboundedSender<OptimizeStatus>,
-    stop_flag: &Arc<AtomicBool>,
-) -> Result<()> {
-    let _ = status_sender.send(OptimizeStatus::Setup);
-
-    let mut asp_data = String::new();
-    asp_data.push_str(&class_atoms(template.class)?);
-    asp_data.push_str(&slot_atoms(template)?);
-    asp_data.push_str(&stat_atoms()?);
-    asp_data.push_str(&item_atoms(items)?);
-    let file_path = Path::new("instance.lp");
-    let _ = std::fs::write(file_path, &asp_data);
-
-    let control = Control::new()?;
-    control.load("instance.lp")?;
-    control.load("src/optimization/encoding.lp")?;
-
-    status_sender.send(OptimizeStatus::Grounding)?;
-    control.ground()?;
-
-    if stop_flag.load(Ordering::Relaxed) {
-        return Ok(());
-    }
-
-    let _ = status_sender.send(OptimizeStatus::Solving);
-
-    let mut handle = control.solve()?;
+/// - `Ok(Template)` containing the template of the model.
+///
+/// # Errors
+/// - `Err(anyhow::Error)` if the assigned items could not be extracted from the model.
+pub fn template_from_model(model: Model, base: &Template, items: &[Arc<Item>]) -> Result<Template> {
+    let assigned_items = assigned_items_from_model(model)?;
 
-    loop {
-        if stop_flag.load(Ordering::Relaxed) {
-            let _ = handle.cancel();
-            let _ = status_sender.send(OptimizeStatus::Finished);
-            break;
-        }
+    let mut template = base.clone();
+    template.slots.clear();
 
-        let ready = handle.wait(0.1);
-
-        if ready {
-            match handle.model() {
-                Ok(Some(model_ref)) => {
-                    let chosen_items = chosen_items_from_model(model_ref)?;
-
-                    let mut new_template = template.clone();
-
-                    for (slot, item_id) in chosen_items {
-                        if let Some(item) = items.iter().find(|i| i.id == item_id) {
-                            new_template.slots.insert(slot, item.clone());
-                        }
-                    }
-
-                    status_sender.send(OptimizeStatus::NewModel(new_template))?;
-
-                    handle.resume()?;
-                }
-                Ok(None) => {
-                    status_sender.send(OptimizeStatus::Finished)?;
-                    break;
-                }
-                Err(e) => {
-                    status_sender.send(OptimizeStatus::Error(e.to_string()))?;
-                    break;
-                }
-            }
+    for (slot, item_id) in assigned_items {
+        if let Some(item) = items.iter().find(|i| i.id == item_id) {
+            template.slots.insert(slot, item.clone());
         }
     }
-    Ok(())
+
+    Ok(template)
 }
 
-/// Extracts the chosen items from a given model.
+/// Extracts the assigned items from a given model.
 ///
 /// # Parameters
-/// - `model`: The model from which to extract the chosen items.
+/// - `model`: The model from which to extract the assigned items.
 ///
 /// # Returns
 /// - `Ok(Vec<(ItemSlot, i32)>)` containing a vector of tuples
@@ -151,7 +147,7 @@ fn run_optimization_logic(
 /// # Errors
 /// - `Err(anyhow::Error)` if an error occurs during the extraction process,
 ///   such as parsing errors or unexpected symbol types.
-fn chosen_items_from_model(model: Model) -> Result<Vec<(ItemSlot, i32)>> {
+fn assigned_items_from_model(model: Model) -> Result<Vec<(ItemSlot, i32)>> {
     let symbols = model.symbols(2)?;
 
     let mut items = Vec::new();
@@ -163,19 +159,19 @@ fn chosen_items_from_model(model: Model) -> Result<Vec<(ItemSlot, i32)>> {
 
         let name = symbol.name()?;
 
-        if name != "slot_chosen" {
+        if name != "slot_assigned" {
             continue;
         }
 
         let arguments = symbol.arguments()?;
 
-        let slot_symbol = arguments
-            .first()
-            .ok_or_else(|| anyhow!("Expected an argument for `slot` at index 0 in slot_chosen"))?;
+        let slot_symbol = arguments.first().ok_or_else(|| {
+            anyhow!("Expected an argument for `slot` at index 0 in slot_assigned")
+        })?;
 
-        let item_symbol = arguments
-            .get(1)
-            .ok_or_else(|| anyhow!("Expected an argument for `item` at index 1 in slot_chosen"))?;
+        let item_symbol = arguments.get(1).ok_or_else(|| {
+            anyhow!("Expected an argument for `item` at index 1 in slot_assigned")
+        })?;
 
         let slot_number = slot_symbol.number().context("Failed to parse slot ID")?;
 